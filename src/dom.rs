@@ -0,0 +1,220 @@
+use core::ops::Range;
+
+use crate::{tape::Slots, ErrorKind, Event, Key, Number, Parser, Result, Scalar, String};
+
+// A node of a document built by `Parser::dom`. Children follow their array or object,
+// and each object member is a `Key` followed by its value.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Node<'buf> {
+    #[default]
+    Null,
+    Boolean(bool),
+    String(String<'buf>),
+    Number(Number<'buf>),
+    Key(Key<'buf>),
+    Array(Container),
+    Object(Container),
+}
+
+// An array or object in a `Dom`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Container {
+    // Number of elements or members.
+    len: usize,
+    // Index of the first node after the last descendant.
+    end: usize,
+    // Index of the enclosing container; the root is its own parent.
+    parent: usize,
+}
+
+impl Container {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Index of the first node after the last descendant.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+}
+
+impl<'buf> Parser<'buf> {
+    // Builds the whole document into `arena`, one node per value and key, and checks
+    // that nothing follows it. Fails with `ArenaFull` if `arena` is too short, and with
+    // `NotAtStart` if part of the document was already read.
+    pub fn dom<'a>(&mut self, arena: &'a mut [Node<'buf>]) -> Result<Dom<'a, 'buf>> {
+        let len = self.build(arena, ErrorKind::ArenaFull)?;
+        Ok(Dom {
+            nodes: &arena[..len],
+        })
+    }
+}
+
+impl<'buf> Slots<'buf> for [Node<'buf>] {
+    fn push(&mut self, index: usize, parent: usize, event: Event<'buf>, _: Range<usize>) -> bool {
+        let Some(slot) = self.get_mut(index) else {
+            return false;
+        };
+        let container = Container {
+            len: 0,
+            end: index + 1,
+            parent,
+        };
+        *slot = match event {
+            Event::StartObject => Node::Object(container),
+            Event::StartArray => Node::Array(container),
+            Event::Key(key) => Node::Key(key),
+            Event::String(s) => Node::String(s),
+            Event::Number(n) => Node::Number(n),
+            Event::Bool(b) => Node::Boolean(b),
+            Event::Null | Event::EndObject | Event::EndArray => Node::Null,
+        };
+        true
+    }
+
+    fn count(&mut self, index: usize) {
+        if let Some(Node::Array(c) | Node::Object(c)) = self.get_mut(index) {
+            c.len += 1;
+        }
+    }
+
+    fn close(&mut self, index: usize, _: usize, next: usize) -> usize {
+        match self.get_mut(index) {
+            Some(Node::Array(c) | Node::Object(c)) => {
+                c.end = next;
+                c.parent
+            }
+            _ => index,
+        }
+    }
+}
+
+// A document built by `Parser::dom`.
+#[derive(Debug, Copy, Clone)]
+pub struct Dom<'a, 'buf> {
+    nodes: &'a [Node<'buf>],
+}
+
+impl<'a, 'buf> Dom<'a, 'buf> {
+    pub fn root(&self) -> NodeRef<'a, 'buf> {
+        NodeRef {
+            nodes: self.nodes,
+            index: 0,
+        }
+    }
+
+    // Number of nodes used.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+// A value in a `Dom`.
+#[derive(Debug, Copy, Clone)]
+pub struct NodeRef<'a, 'buf> {
+    nodes: &'a [Node<'buf>],
+    index: usize,
+}
+
+impl<'a, 'buf> NodeRef<'a, 'buf> {
+    pub fn node(&self) -> &'a Node<'buf> {
+        &self.nodes[self.index]
+    }
+
+    // Number of elements or members, zero for scalars.
+    pub fn len(&self) -> usize {
+        match self.node() {
+            Node::Array(c) | Node::Object(c) => c.len,
+            _ => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn scalar(&self) -> Option<Scalar<'buf>> {
+        Some(match *self.node() {
+            Node::Null => Scalar::Null,
+            Node::Boolean(b) => Scalar::Boolean(b),
+            Node::String(s) => Scalar::String(s),
+            Node::Number(n) => Scalar::Number(n),
+            Node::Key(_) | Node::Array(_) | Node::Object(_) => return None,
+        })
+    }
+
+    // The element at `index` of an array.
+    pub fn at(&self, index: usize) -> Option<NodeRef<'a, 'buf>> {
+        match self.node() {
+            Node::Array(c) if index < c.len => {
+                let mut child = self.index + 1;
+                for _ in 0..index {
+                    child = self.skip(child);
+                }
+                Some(self.with_index(child))
+            }
+            _ => None,
+        }
+    }
+
+    // The value of the first member with `key`, compared with the decoded keys.
+    pub fn get(&self, key: &str) -> Option<NodeRef<'a, 'buf>> {
+        self.members().find(|(k, _)| k.matches(key)).map(|(_, v)| v)
+    }
+
+    // Elements of an array; empty for anything else.
+    pub fn elements(&self) -> impl Iterator<Item = NodeRef<'a, 'buf>> + 'a {
+        let this = *self;
+        let len = match self.node() {
+            Node::Array(c) => c.len,
+            _ => 0,
+        };
+        let mut child = self.index + 1;
+        (0..len).map(move |_| {
+            let element = this.with_index(child);
+            child = this.skip(child);
+            element
+        })
+    }
+
+    // Members of an object; empty for anything else.
+    pub fn members(&self) -> impl Iterator<Item = (Key<'buf>, NodeRef<'a, 'buf>)> + 'a {
+        let this = *self;
+        let len = match self.node() {
+            Node::Object(c) => c.len,
+            _ => 0,
+        };
+        let mut child = self.index + 1;
+        (0..len).filter_map(move |_| {
+            let Node::Key(key) = this.nodes[child] else {
+                return None;
+            };
+            let value = this.with_index(child + 1);
+            child = this.skip(child + 1);
+            Some((key, value))
+        })
+    }
+
+    // Index of the first node after the subtree at `index`.
+    fn skip(&self, index: usize) -> usize {
+        match self.nodes[index] {
+            Node::Array(c) | Node::Object(c) => c.end,
+            _ => index + 1,
+        }
+    }
+
+    fn with_index(&self, index: usize) -> NodeRef<'a, 'buf> {
+        NodeRef {
+            nodes: self.nodes,
+            index,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use core::ops::Range;
+
+use crate::{ErrorKind, Key, Number, Parser, Result, String, Val};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Event<'buf> {
+    StartObject,
+    Key(Key<'buf>),
+    EndObject,
+    StartArray,
+    EndArray,
+    String(String<'buf>),
+    Number(Number<'buf>),
+    Bool(bool),
+    Null,
+}
+
+impl<'buf> Parser<'buf> {
+    // Reads the next token of the document, without recursion. Returns `None` once the
+    // top-level value is complete; `finalize` checks that nothing follows it.
+    //
+    // The state between calls is the previous byte: an opening bracket, the colon after
+    // a key, or the end of a value.
+    pub fn next_event(&mut self) -> Result<Option<Event<'buf>>> {
+        let prev = match self.offset() {
+            0 => None,
+            offset => Some(self.json.buffer[offset - 1]),
+        };
+        let Some(in_object) = self.nesting.in_object() else {
+            if prev.is_some() {
+                return Ok(None);
+            }
+            return self.value_event().map(Some);
+        };
+        if prev == Some(b':') {
+            return self.value_event().map(Some);
+        }
+
+        let opened = matches!(prev, Some(b'[' | b'{'));
+        self.skip_ws();
+        let (close, other) = if in_object {
+            (b'}', b']')
+        } else {
+            (b']', b'}')
+        };
+        match self.peek()? {
+            c if c == close => {
+                self.step()?;
+                self.ascend();
+                return Ok(Some(if in_object {
+                    Event::EndObject
+                } else {
+                    Event::EndArray
+                }));
+            }
+            c if c == other => return Err(self.error(ErrorKind::MismatchedBracket)),
+            b',' if !opened => self.step()?,
+            _ if !opened => return Err(self.error(ErrorKind::ExpectedCommaOrClose)),
+            _ => (),
+        }
+
+        if !in_object {
+            return self.value_event().map(Some);
+        }
+        self.skip_ws();
+        let key = Key {
+            key: self.parse_string()?.as_str(),
+        };
+        self.skip_ws();
+        if let b':' = self.peek()? {
+            self.step()?;
+        } else {
+            return Err(self.error(ErrorKind::ExpectedColon));
+        }
+        Ok(Some(Event::Key(key)))
+    }
+
+    // Span of the token of the event just read: a scalar, a bracket, or a quoted key.
+    pub(crate) fn event_span(&self, event: &Event<'_>) -> Range<usize> {
+        let mut end = self.offset();
+        let len = match event {
+            Event::Key(key) => {
+                // The colon and the whitespace before it were consumed with the key.
+                end -= 1;
+                while let b' ' | b'\r' | b'\n' | b'\t' = self.json.buffer[end - 1] {
+                    end -= 1;
+                }
+                key.key.len() + 2
+            }
+            Event::String(s) => s.string.len() + 2,
+            Event::Number(n) => n.number.len(),
+            Event::Bool(false) => 5,
+            Event::Bool(true) | Event::Null => 4,
+            _ => 1,
+        };
+        end - len..end
+    }
+
+    // Number of open arrays and objects.
+    pub fn depth(&self) -> usize {
+        self.nesting.depth
+    }
+
+    fn value_event(&mut self) -> Result<Event<'buf>> {
+        self.skip_ws();
+        Ok(match Val::from(self)? {
+            Val::Null(_) => Event::Null,
+            Val::Boolean(b, _) => Event::Bool(b),
+            Val::String(s) => Event::String(s),
+            Val::Number(n) => Event::Number(n),
+            Val::Array(_) => Event::StartArray,
+            Val::Object(_) => Event::StartObject,
+        })
+    }
+}
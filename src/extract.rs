@@ -0,0 +1,111 @@
+use crate::{pointer::Segment, Error, ErrorKind, Parser, Result, Scalar, Seg, Val};
+
+fn expected_scalar(val: &Val<'_, '_>) -> Error {
+    // Points to the opening bracket.
+    let offset = match val {
+        Val::Array(a) => a.start - 1,
+        Val::Object(o) => o.start - 1,
+        _ => 0,
+    };
+    Error {
+        kind: ErrorKind::ExpectedScalar,
+        offset,
+    }
+}
+
+struct Extractor<'p, 's, 'o, 'buf, const N: usize> {
+    paths: [&'p [Seg<'s>]; N],
+    slots: &'o mut [Option<Scalar<'buf>>; N],
+    remaining: usize,
+}
+
+impl<'buf, const N: usize> Extractor<'_, '_, '_, 'buf, N> {
+    // Fills the slots of the paths in `alive` that end at `val`, and descends into `val`
+    // for the ones that continue. Returns `true` once every slot is filled.
+    //
+    // Recurses only while some path continues, so the depth is bounded by the longest path.
+    fn visit(&mut self, val: Val<'_, 'buf>, depth: usize, alive: &[bool; N]) -> Result<bool> {
+        let mut deeper = false;
+        for (i, path) in self.paths.iter().enumerate() {
+            if !alive[i] {
+                continue;
+            }
+            if path.len() > depth {
+                deeper = true;
+            } else if self.slots[i].is_none() {
+                self.slots[i] = Some(val.scalar().ok_or_else(|| expected_scalar(&val))?);
+                self.remaining -= 1;
+            }
+        }
+        if self.remaining == 0 {
+            return Ok(true);
+        }
+        if !deeper {
+            return Ok(false);
+        }
+
+        let mut child = [false; N];
+        match val {
+            Val::Object(mut o) => {
+                let paths = self.paths;
+                while o.seek(|key| {
+                    let mut any = false;
+                    for (i, path) in paths.iter().enumerate() {
+                        child[i] = alive[i] && path.get(depth).is_some_and(|s| s.matches_key(key));
+                        any |= child[i];
+                    }
+                    any
+                })? {
+                    let val = Val::from(o.parser)?;
+                    if self.visit(val, depth + 1, &child)? {
+                        return Ok(true);
+                    }
+                }
+            }
+            Val::Array(mut a) => {
+                let mut index = 0;
+                while a.advance()? {
+                    let mut any = false;
+                    for (i, path) in self.paths.iter().enumerate() {
+                        child[i] =
+                            alive[i] && path.get(depth).and_then(|s| s.index()) == Some(index);
+                        any |= child[i];
+                    }
+                    let val = Val::from(a.parser)?;
+                    if any && self.visit(val, depth + 1, &child)? {
+                        return Ok(true);
+                    }
+                    index += 1;
+                }
+            }
+            _ => (),
+        }
+        Ok(false)
+    }
+}
+
+impl<'buf> Parser<'buf> {
+    // Looks up all `paths` in one pass over the value at the current position, storing
+    // what they point to in the corresponding `slots`. Paths that aren't found leave their
+    // slot `None`, and paths pointing to an array or object fail with `ExpectedScalar`.
+    //
+    // Returns as soon as every slot is filled; call `finalize` to validate the rest.
+    pub fn extract<const N: usize>(
+        &mut self,
+        paths: [&[Seg<'_>]; N],
+        slots: &mut [Option<Scalar<'buf>>; N],
+    ) -> Result<()> {
+        *slots = [None; N];
+        if N == 0 {
+            return Ok(());
+        }
+        let mut extractor = Extractor {
+            paths,
+            slots,
+            remaining: N,
+        };
+        let val = self.value()?;
+        extractor.visit(val, 0, &[true; N])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{Array, Error, ErrorKind, Key, Number, Object, Result, Scalar, String, Val};
+
+impl<'a, 'buf> Array<'a, 'buf> {
+    // Elements as numbers. Anything else is an `ExpectedNumber` error, after which the
+    // iterator ends.
+    pub fn numbers<'s>(&'s mut self) -> Numbers<'s, 'a, 'buf> {
+        Numbers {
+            array: self,
+            done: false,
+        }
+    }
+
+    // Elements as strings. Anything else is an `ExpectedString` error, after which the
+    // iterator ends.
+    pub fn strings<'s>(&'s mut self) -> Strings<'s, 'a, 'buf> {
+        Strings {
+            array: self,
+            done: false,
+        }
+    }
+
+    // Calls `f` with each remaining element. Unread arrays and objects are skipped.
+    pub fn for_each(&mut self, mut f: impl FnMut(Val<'_, 'buf>)) -> Result<()> {
+        while let Some(val) = self.next()? {
+            f(val);
+        }
+        Ok(())
+    }
+
+    // Like `for_each`, stopping at the first error returned by `f`.
+    pub fn try_for_each<E: From<Error>>(
+        &mut self,
+        mut f: impl FnMut(Val<'_, 'buf>) -> core::result::Result<(), E>,
+    ) -> core::result::Result<(), E> {
+        while let Some(val) = self.next()? {
+            f(val)?;
+        }
+        Ok(())
+    }
+
+    // Reads the next element, failing with `kind` unless `pick` accepts it.
+    fn next_as<T>(
+        &mut self,
+        kind: ErrorKind,
+        pick: fn(Scalar<'buf>) -> Option<T>,
+    ) -> Result<Option<T>> {
+        if !self.advance()? {
+            return Ok(None);
+        }
+        let offset = self.parser.offset();
+        Val::from(self.parser)?
+            .scalar()
+            .and_then(pick)
+            .map(Some)
+            .ok_or(Error { kind, offset })
+    }
+}
+
+impl<'a, 'buf> Object<'a, 'buf> {
+    // Members with scalar values. An array or object is an `ExpectedScalar` error,
+    // after which the iterator ends.
+    pub fn scalar_entries<'s>(&'s mut self) -> ScalarEntries<'s, 'a, 'buf> {
+        ScalarEntries {
+            object: self,
+            done: false,
+        }
+    }
+
+    // Calls `f` with each remaining member. Unread arrays and objects are skipped.
+    pub fn for_each(&mut self, mut f: impl FnMut(Key<'buf>, Val<'_, 'buf>)) -> Result<()> {
+        while let Some((key, val)) = self.next()? {
+            f(key, val);
+        }
+        Ok(())
+    }
+
+    // Like `for_each`, stopping at the first error returned by `f`.
+    pub fn try_for_each<E: From<Error>>(
+        &mut self,
+        mut f: impl FnMut(Key<'buf>, Val<'_, 'buf>) -> core::result::Result<(), E>,
+    ) -> core::result::Result<(), E> {
+        while let Some((key, val)) = self.next()? {
+            f(key, val)?;
+        }
+        Ok(())
+    }
+
+    fn next_scalar(&mut self) -> Result<Option<(Key<'buf>, Scalar<'buf>)>> {
+        let Some((key, _)) = self.advance()? else {
+            return Ok(None);
+        };
+        let offset = self.parser.offset();
+        match Val::from(self.parser)?.scalar() {
+            Some(scalar) => Ok(Some((key, scalar))),
+            None => Err(Error {
+                kind: ErrorKind::ExpectedScalar,
+                offset,
+            }),
+        }
+    }
+}
+
+// Ends the iteration after `None` or the first error.
+fn fuse<T>(done: &mut bool, item: Result<Option<T>>) -> Option<Result<T>> {
+    let item = item.transpose();
+    *done = !matches!(item, Some(Ok(_)));
+    item
+}
+
+#[derive(Debug)]
+pub struct Numbers<'s, 'a, 'buf> {
+    array: &'s mut Array<'a, 'buf>,
+    done: bool,
+}
+
+impl<'buf> Iterator for Numbers<'_, '_, 'buf> {
+    type Item = Result<Number<'buf>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.array.next_as(ErrorKind::ExpectedNumber, |s| match s {
+            Scalar::Number(n) => Some(n),
+            _ => None,
+        });
+        fuse(&mut self.done, item)
+    }
+}
+
+#[derive(Debug)]
+pub struct Strings<'s, 'a, 'buf> {
+    array: &'s mut Array<'a, 'buf>,
+    done: bool,
+}
+
+impl<'buf> Iterator for Strings<'_, '_, 'buf> {
+    type Item = Result<String<'buf>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.array.next_as(ErrorKind::ExpectedString, |s| match s {
+            Scalar::String(s) => Some(s),
+            _ => None,
+        });
+        fuse(&mut self.done, item)
+    }
+}
+
+#[derive(Debug)]
+pub struct ScalarEntries<'s, 'a, 'buf> {
+    object: &'s mut Object<'a, 'buf>,
+    done: bool,
+}
+
+impl<'buf> Iterator for ScalarEntries<'_, '_, 'buf> {
+    type Item = Result<(Key<'buf>, Scalar<'buf>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.object.next_scalar();
+        fuse(&mut self.done, item)
+    }
+}
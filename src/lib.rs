@@ -1,24 +1,209 @@
-//#![no_std]
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod dom;
+mod event;
+mod extract;
+mod iter;
+mod number;
+mod pointer;
+mod string;
+mod tape;
+#[cfg(feature = "alloc")]
+mod value;
+mod visit;
+
+pub use dom::{Container, Dom, Node, NodeRef};
+pub use event::Event;
+pub use iter::{Numbers, ScalarEntries, Strings};
+pub use number::{NumberError, Rounding};
+pub use pointer::Seg;
+pub use string::{Chars, DecodedBytes};
+pub use tape::{Document, EntryKind, TapeEntry, TapeRef};
+#[cfg(feature = "alloc")]
+pub use value::Value;
+pub use visit::{Flow, Visitor};
 
 use core::{
     fmt::{self, Debug, Display},
+    ops::Range,
     str::from_utf8,
 };
 
 pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Error;
+pub enum ErrorKind {
+    UnexpectedEof,
+    ExpectedValue,
+    ExpectedString,
+    ExpectedColon,
+    ExpectedCommaOrClose,
+    MismatchedBracket,
+    UnknownLiteral,
+    InvalidNumber,
+    InvalidEscape,
+    ControlCharInString,
+    InvalidUtf8,
+    TrailingData,
+    TooDeep,
+    LoneSurrogate,
+    BufferTooSmall,
+    InvalidPointer,
+    ExpectedScalar,
+    ExpectedNumber,
+    TapeFull,
+    ArenaFull,
+    InvalidConfig,
+    NotAtStart,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ErrorKind::UnexpectedEof => "unexpected end of input",
+            ErrorKind::ExpectedValue => "expected a value",
+            ErrorKind::ExpectedString => "expected a string",
+            ErrorKind::ExpectedColon => "expected ':'",
+            ErrorKind::ExpectedCommaOrClose => "expected ',' or a closing bracket",
+            ErrorKind::MismatchedBracket => "closing bracket doesn't match the opening one",
+            ErrorKind::UnknownLiteral => "unknown literal, expected null, true or false",
+            ErrorKind::InvalidNumber => "invalid number",
+            ErrorKind::InvalidEscape => "invalid escape sequence in string",
+            ErrorKind::ControlCharInString => "unescaped control character in string",
+            ErrorKind::InvalidUtf8 => "invalid UTF-8 in string",
+            ErrorKind::TrailingData => "trailing data after the value",
+            ErrorKind::TooDeep => "maximum nesting depth exceeded",
+            ErrorKind::LoneSurrogate => "unpaired UTF-16 surrogate in string",
+            ErrorKind::BufferTooSmall => "output buffer is too small",
+            ErrorKind::InvalidPointer => "invalid JSON pointer",
+            ErrorKind::ExpectedScalar => "expected a scalar, found an array or object",
+            ErrorKind::ExpectedNumber => "expected a number",
+            ErrorKind::TapeFull => "tape capacity exceeded",
+            ErrorKind::ArenaFull => "arena capacity exceeded",
+            ErrorKind::InvalidConfig => "invalid parser configuration",
+            ErrorKind::NotAtStart => "part of the document was already read",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Error {
+    kind: ErrorKind,
+    offset: usize,
+}
+
+impl Error {
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    // Byte offset into the input at which the error was detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
 
 impl Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Error")
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.kind, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
+
+// Hard upper bound for `ParserConfig::max_depth`, set by the size of `Nesting.objects`.
+pub const MAX_DEPTH: usize = 128;
+
+// Options for `parse_with`. The fields are private so that options can be added
+// without breaking callers, and so that every config is valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserConfig {
+    max_depth: usize,
+    strict_surrogates: bool,
+}
+
+impl ParserConfig {
+    // Fails with `ErrorKind::InvalidConfig` at offset 0 if `max_depth` is above
+    // `MAX_DEPTH`, the default.
+    pub fn with_max_depth(self, max_depth: usize) -> Result<ParserConfig> {
+        if max_depth > MAX_DEPTH {
+            return Err(Error {
+                kind: ErrorKind::InvalidConfig,
+                offset: 0,
+            });
+        }
+        Ok(ParserConfig { max_depth, ..self })
+    }
+
+    // Reject `\u` escapes that are unpaired UTF-16 surrogates, as they can't be decoded.
+    pub fn with_strict_surrogates(self, strict_surrogates: bool) -> ParserConfig {
+        ParserConfig {
+            strict_surrogates,
+            ..self
+        }
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn strict_surrogates(&self) -> bool {
+        self.strict_surrogates
+    }
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        ParserConfig {
+            max_depth: MAX_DEPTH,
+            strict_surrogates: false,
+        }
     }
 }
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Nesting {
     depth: usize,
+    // Bit `n` is set if the container at depth `n + 1` is an object.
+    objects: u128,
+}
+
+impl Nesting {
+    // INVARIANT C: `depth` never exceeds `MAX_DEPTH`; `Parser::descend` checks it.
+    fn push(&mut self, object: bool) {
+        let bit = 1 << self.depth;
+        if object {
+            self.objects |= bit;
+        } else {
+            self.objects &= !bit;
+        }
+        self.depth += 1;
+    }
+
+    fn pop(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn in_object(&self) -> Option<bool> {
+        match self.depth {
+            0 => None,
+            d => Some(self.objects & (1 << (d - 1)) != 0),
+        }
+    }
 }
 
 impl Debug for Nesting {
@@ -45,8 +230,41 @@ impl Debug for Buffer<'_> {
     }
 }
 
+impl Buffer<'_> {
+    pub fn position(&self, offset: usize) -> Position {
+        let before = &self.buffer[..offset.min(self.buffer.len())];
+        let line_start = match before.iter().rposition(|&b| b == b'\n') {
+            Some(newline) => newline + 1,
+            None => 0,
+        };
+        Position {
+            line: before.iter().filter(|&&b| b == b'\n').count() + 1,
+            // Count only UTF-8 leading bytes, so that each character counts once.
+            column: before[line_start..]
+                .iter()
+                .filter(|&&b| b & 0b1100_0000 != 0b1000_0000)
+                .count()
+                + 1,
+        }
+    }
+}
+
+// 1-based line and column (counted in characters) of a byte offset.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct Parser<'buf> {
+    config: ParserConfig,
     nesting: Nesting,
     json: Buffer<'buf>,
 }
@@ -61,8 +279,16 @@ impl Debug for Parser<'_> {
 }
 
 pub fn parse(json: &[u8]) -> Parser<'_> {
+    parse_with(json, ParserConfig::default())
+}
+
+pub fn parse_with(json: &[u8], config: ParserConfig) -> Parser<'_> {
     Parser {
-        nesting: Nesting { depth: 0 },
+        config,
+        nesting: Nesting {
+            depth: 0,
+            objects: 0,
+        },
         json: Buffer {
             offset: 0,
             buffer: json,
@@ -70,47 +296,123 @@ pub fn parse(json: &[u8]) -> Parser<'_> {
     }
 }
 
+// Reads the whole of `reader` into `buf` and starts parsing it.
+#[cfg(feature = "std")]
+pub fn parse_reader(mut reader: impl std::io::Read, buf: &mut [u8]) -> std::io::Result<Parser<'_>> {
+    let mut len = 0;
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]) {
+            Ok(0) => return Ok(parse(&buf[..len])),
+            Ok(n) => len += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+    // The buffer is full; any more input would be cut off.
+    loop {
+        match reader.read(&mut [0]) {
+            Ok(0) => return Ok(parse(buf)),
+            Ok(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "input doesn't fit into the buffer",
+                ))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
 impl<'buf> Parser<'buf> {
-    pub fn descend(&mut self) {
-        self.nesting.depth += 1;
+    pub fn descend(&mut self, object: bool) -> Result<()> {
+        if self.nesting.depth >= self.config.max_depth {
+            return Err(self.error(ErrorKind::TooDeep));
+        }
+        self.nesting.push(object);
+        Ok(())
     }
 
     pub fn ascend(&mut self) {
-        self.nesting.depth -= 1;
+        self.nesting.pop();
     }
 
     pub fn offset(&self) -> usize {
         self.json.offset
     }
 
+    pub fn position(&self, offset: usize) -> Position {
+        self.json.position(offset)
+    }
+
+    fn error(&self, kind: ErrorKind) -> Error {
+        Error {
+            kind,
+            offset: self.offset(),
+        }
+    }
+
     // INVARIANT B: if peek succeeds, one call to step followed by that also succeeds
     pub fn peek(&self) -> Result<u8> {
-        self.json.buffer.get(self.offset()).copied().ok_or(Error)
+        self.json
+            .buffer
+            .get(self.offset())
+            .copied()
+            .ok_or(self.error(ErrorKind::UnexpectedEof))
     }
 
     // INVARIANT B: if peek succeeds, one call to step followed by that also succeeds
     pub fn step(&mut self) -> Result<()> {
         if self.offset() == self.json.buffer.len() {
-            return Err(Error);
+            return Err(self.error(ErrorKind::UnexpectedEof));
         }
         self.json.offset += 1;
         Ok(())
     }
 
+    // Skips and validates the rest of the open containers down to `depth`.
+    // Must be called right after an opening bracket or a complete value.
     pub fn ascend_to(&mut self, depth: usize) -> Result<()> {
+        // Whether we are right after an opening bracket, i.e. the container may be empty,
+        // and its first member has no comma in front of it.
+        let mut opened = self.offset() > 0
+            && matches!(self.json.buffer.get(self.offset() - 1), Some(b'[' | b'{'));
         while depth < self.nesting.depth {
+            self.skip_ws();
+            let in_object = self.nesting.in_object() == Some(true);
+            let (close, other) = if in_object {
+                (b'}', b']')
+            } else {
+                (b']', b'}')
+            };
             match self.peek()? {
-                b'}' => self.ascend(),
-                b']' => self.ascend(),
-                b'{' => self.descend(),
-                b'[' => self.descend(),
-                b'"' => {
-                    self.parse_string()?;
+                c if c == close => {
+                    self.step()?;
+                    self.ascend();
+                    opened = false;
                     continue;
                 }
+                c if c == other => return Err(self.error(ErrorKind::MismatchedBracket)),
+                b',' if !opened => {
+                    self.step()?;
+                    self.skip_ws();
+                }
+                _ if !opened => return Err(self.error(ErrorKind::ExpectedCommaOrClose)),
                 _ => (),
             }
-            self.step()?;
+
+            if in_object {
+                self.parse_string()?;
+                self.skip_ws();
+                if let b':' = self.peek()? {
+                    self.step()?;
+                } else {
+                    return Err(self.error(ErrorKind::ExpectedColon));
+                }
+                self.skip_ws();
+            }
+            // Containers are entered without recursion; scalars are consumed whole.
+            opened = matches!(Val::from(self)?, Val::Array(_) | Val::Object(_));
         }
         Ok(())
     }
@@ -120,11 +422,26 @@ impl<'buf> Parser<'buf> {
         Val::from(self)
     }
 
+    pub fn value_spanned<'a>(&'a mut self) -> Result<Spanned<'a, 'buf>> {
+        self.skip_ws();
+        Spanned::from(self)
+    }
+
+    // Skips and validates the value at the current position, returning its span.
+    pub fn skip_value(&mut self) -> Result<Range<usize>> {
+        self.skip_ws();
+        let start = self.offset();
+        let depth = self.nesting.depth;
+        Val::from(self)?;
+        self.ascend_to(depth)?;
+        Ok(start..self.offset())
+    }
+
     pub fn finalize(&mut self) -> Result<()> {
         self.ascend_to(0)?;
         self.skip_ws();
         if self.offset() != self.json.buffer.len() {
-            return Err(Error);
+            return Err(self.error(ErrorKind::TrailingData));
         }
         Ok(())
     }
@@ -150,7 +467,7 @@ impl<'buf> Parser<'buf> {
                     self.step()?;
                 }
             }
-            _ => return Err(Error),
+            _ => return Err(self.error(ErrorKind::InvalidNumber)),
         }
 
         if let Ok(b'.') = self.peek() {
@@ -158,7 +475,7 @@ impl<'buf> Parser<'buf> {
             if let b'0'..=b'9' = self.peek()? {
                 self.step()?;
             } else {
-                return Err(Error);
+                return Err(self.error(ErrorKind::InvalidNumber));
             }
             while let Ok(b'0'..=b'9') = self.peek() {
                 self.step()?;
@@ -173,7 +490,7 @@ impl<'buf> Parser<'buf> {
             if let b'0'..=b'9' = self.peek()? {
                 self.step()?;
             } else {
-                return Err(Error);
+                return Err(self.error(ErrorKind::InvalidNumber));
             }
             while let Ok(b'0'..=b'9') = self.peek() {
                 self.step()?;
@@ -182,6 +499,7 @@ impl<'buf> Parser<'buf> {
 
         Ok(Number {
             number: &self.json.buffer[start..self.offset()],
+            start,
         })
     }
 
@@ -189,18 +507,39 @@ impl<'buf> Parser<'buf> {
         if let b'"' = self.peek()? {
             self.step()?;
         } else {
-            return Err(Error);
+            return Err(self.error(ErrorKind::ExpectedString));
         }
         let start = self.offset();
+        let mut escaped = false;
+        // Offset of a high surrogate escape still waiting for its low surrogate.
+        let mut high_surrogate = None;
         loop {
+            if let Some(offset) = high_surrogate {
+                if !self.json.buffer[self.offset()..].starts_with(b"\\u") {
+                    return Err(Error {
+                        kind: ErrorKind::LoneSurrogate,
+                        offset,
+                    });
+                }
+            }
             match self.peek()? {
                 b'"' => {
                     let contents = &self.json.buffer[start..self.offset()];
                     self.step()?;
-                    let validated = from_utf8(contents).map_err(|_| Error)?;
-                    return Ok(String { string: validated });
+                    let validated = from_utf8(contents).map_err(|e| Error {
+                        kind: ErrorKind::InvalidUtf8,
+                        offset: start + e.valid_up_to(),
+                    })?;
+                    return Ok(String {
+                        string: validated,
+                        quoted: Some(&self.json.buffer[start - 1..self.offset()]),
+                        escaped,
+                        start: start - 1,
+                    });
                 }
                 b'\\' => {
+                    escaped = true;
+                    let escape_start = self.offset();
                     self.step()?;
                     match self.peek()? {
                         b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' => {
@@ -208,22 +547,38 @@ impl<'buf> Parser<'buf> {
                         }
                         b'u' => {
                             self.step()?;
+                            let mut code = 0u16;
                             for _ in 0..4 {
-                                match self.peek()? {
-                                    b'0'..=b'9' | b'A'..=b'F' | b'a'..=b'f' => {
-                                        self.step()?;
-                                    }
-                                    _ => return Err(Error),
-                                }
+                                let digit = match self.peek()? {
+                                    c @ b'0'..=b'9' => c - b'0',
+                                    c @ b'a'..=b'f' => c - b'a' + 10,
+                                    c @ b'A'..=b'F' => c - b'A' + 10,
+                                    _ => return Err(self.error(ErrorKind::InvalidEscape)),
+                                };
+                                code = code << 4 | digit as u16;
+                                self.step()?;
+                            }
+                            if self.config.strict_surrogates {
+                                let lone = |offset| Error {
+                                    kind: ErrorKind::LoneSurrogate,
+                                    offset,
+                                };
+                                high_surrogate = match (high_surrogate, code) {
+                                    (None, 0xD800..=0xDBFF) => Some(escape_start),
+                                    (None, 0xDC00..=0xDFFF) => return Err(lone(escape_start)),
+                                    (None, _) => None,
+                                    (Some(_), 0xDC00..=0xDFFF) => None,
+                                    (Some(offset), _) => return Err(lone(offset)),
+                                };
                             }
                         }
-                        _ => return Err(Error),
+                        _ => return Err(self.error(ErrorKind::InvalidEscape)),
                     }
                 }
                 // Don't allow control characters (0..32).
                 // UTF-8 continuation bytes are always of form 10xxxxxx (128..192),
                 // so they are unaffected.
-                c if c < 32 => return Err(Error),
+                c if c < 32 => return Err(self.error(ErrorKind::ControlCharInString)),
                 _ => self.step()?,
             }
         }
@@ -231,50 +586,72 @@ impl<'buf> Parser<'buf> {
 
     pub fn parse_array<'a>(&'a mut self) -> Result<Array<'a, 'buf>> {
         if let b'[' = self.peek()? {
+            self.descend(false)?;
             self.step()?;
-            self.descend();
             Ok(Array {
                 start: self.json.offset,
                 depth: self.nesting.depth,
                 parser: self,
             })
         } else {
-            Err(Error)
+            Err(self.error(ErrorKind::ExpectedValue))
         }
     }
 
     pub fn parse_object<'a>(&'a mut self) -> Result<Object<'a, 'buf>> {
         if let b'{' = self.peek()? {
+            self.descend(true)?;
             self.step()?;
-            self.descend();
             Ok(Object {
                 start: self.json.offset,
                 depth: self.nesting.depth,
                 parser: self,
             })
         } else {
-            Err(Error)
+            Err(self.error(ErrorKind::ExpectedValue))
         }
     }
 
-    pub fn parse_word<'a>(&'a mut self, word: &[u8]) -> Result<()> {
-        if let Some(json) = self.json.buffer.get(self.json.offset..) {
+    pub fn parse_word(&mut self, word: &[u8]) -> Result<Literal<'buf>> {
+        let start = self.json.offset;
+        if let Some(json) = self.json.buffer.get(start..) {
             if json.starts_with(word) {
                 self.json.offset += word.len();
-                return Ok(());
+                return Ok(Literal {
+                    literal: &json[..word.len()],
+                    start,
+                });
+            }
+            if word.starts_with(json) {
+                // Points to where the input ended.
+                return Err(Error {
+                    kind: ErrorKind::UnexpectedEof,
+                    offset: self.json.buffer.len(),
+                });
             }
         }
-        Err(Error)
+        Err(self.error(ErrorKind::UnknownLiteral))
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct Number<'buf> {
     // INVARIANT A:
     // `Number.number` can only ever contain bytes b'-', b'+', b'0'..b'9', b'.', b'e', b'E'.
     number: &'buf [u8],
+    // Offset of `number` in the input.
+    start: usize,
 }
 
+// Numbers compare by their text, wherever they were read from.
+impl PartialEq for Number<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.number == other.number
+    }
+}
+
+impl Eq for Number<'_> {}
+
 impl<'buf> Number<'buf> {
     pub fn as_bytes(&self) -> &'buf [u8] {
         self.number
@@ -286,11 +663,27 @@ impl<'buf> Number<'buf> {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct String<'buf> {
     string: &'buf str,
+    // The source text, including the quotes. `None` for strings that weren't parsed,
+    // such as those borrowed from a `Value`.
+    quoted: Option<&'buf [u8]>,
+    // Whether `string` contains any escape sequences, recorded by `parse_string`.
+    escaped: bool,
+    // Offset of the opening quote in the input.
+    start: usize,
+}
+
+// Strings compare by their contents, wherever they were read from.
+impl PartialEq for String<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.string == other.string
+    }
 }
 
+impl Eq for String<'_> {}
+
 impl<'buf> String<'buf> {
     pub fn as_str(&self) -> &'buf str {
         self.string
@@ -299,6 +692,19 @@ impl<'buf> String<'buf> {
     pub fn as_bytes(&self) -> &'buf [u8] {
         self.string.as_bytes()
     }
+
+    pub fn has_escapes(&self) -> bool {
+        self.escaped
+    }
+
+    // The contents, if they need no unescaping.
+    pub fn as_unescaped_str(&self) -> Option<&'buf str> {
+        if self.escaped {
+            None
+        } else {
+            Some(self.string)
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -310,12 +716,46 @@ pub struct Array<'a, 'buf> {
 
 impl<'a, 'buf> Array<'a, 'buf> {
     pub fn next<'b>(&'b mut self) -> Result<Option<Val<'b, 'buf>>> {
+        if self.advance()? {
+            Ok(Some(Val::from(self.parser)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn next_spanned<'b>(&'b mut self) -> Result<Option<Spanned<'b, 'buf>>> {
+        if self.advance()? {
+            Ok(Some(Spanned::from(self.parser)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // Skips and validates the remaining elements, returning the span of the whole array.
+    pub fn skip_rest(self) -> Result<Range<usize>> {
+        self.parser.ascend_to(self.depth - 1)?;
+        Ok(self.start - 1..self.parser.offset())
+    }
+
+    // Span of the whole array, once `next` has returned `None`.
+    pub fn span(&self) -> Option<Range<usize>> {
+        // Nothing else can move the parser while it is borrowed, so it is still
+        // right after the closing bracket.
+        (self.parser.nesting.depth < self.depth).then(|| self.start - 1..self.parser.offset())
+    }
+
+    // Moves the parser to the start of the next element, or past the closing bracket.
+    fn advance(&mut self) -> Result<bool> {
+        if self.parser.nesting.depth < self.depth {
+            // Already closed.
+            return Ok(false);
+        }
         if self.parser.offset() == self.start {
             self.parser.skip_ws();
             if let b']' = self.parser.peek()? {
                 self.parser.step()?;
                 self.parser.ascend();
-                return Ok(None);
+                return Ok(false);
             }
         } else {
             self.parser.ascend_to(self.depth)?;
@@ -328,12 +768,12 @@ impl<'a, 'buf> Array<'a, 'buf> {
                 b']' => {
                     self.parser.step()?;
                     self.parser.ascend();
-                    return Ok(None);
+                    return Ok(false);
                 }
-                _ => return Err(Error),
+                _ => return Err(self.parser.error(ErrorKind::ExpectedCommaOrClose)),
             }
         }
-        Ok(Some(Val::from(self.parser)?))
+        Ok(true)
     }
 }
 
@@ -346,6 +786,99 @@ pub struct Object<'a, 'buf> {
 
 impl<'a, 'buf> Object<'a, 'buf> {
     pub fn next<'b>(&'b mut self) -> Result<Option<(Key<'buf>, Val<'b, 'buf>)>> {
+        match self.advance()? {
+            Some((key, _)) => Ok(Some((key, Val::from(self.parser)?))),
+            None => Ok(None),
+        }
+    }
+
+    // The key span includes the quotes.
+    pub fn next_spanned<'b>(
+        &'b mut self,
+    ) -> Result<Option<(Key<'buf>, Range<usize>, Spanned<'b, 'buf>)>> {
+        match self.advance()? {
+            Some((key, span)) => Ok(Some((key, span, Spanned::from(self.parser)?))),
+            None => Ok(None),
+        }
+    }
+
+    // Scans the remaining members for `key`, comparing it with the decoded keys.
+    // If it isn't found, the object is read to the end.
+    pub fn get<'b>(&'b mut self, key: &str) -> Result<Option<Val<'b, 'buf>>> {
+        if self.seek(|k| k.matches(key))? {
+            Ok(Some(Val::from(self.parser)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // Moves the parser to the value of the next member whose key satisfies `matches`.
+    // Returns `false` once the object is closed. The values of the other members are only
+    // started, to step over them: an array or object among them is validated and skipped
+    // by the next `advance`, through `Parser::ascend_to`.
+    pub(crate) fn seek(&mut self, mut matches: impl FnMut(&Key<'buf>) -> bool) -> Result<bool> {
+        while let Some((key, _)) = self.advance()? {
+            if matches(&key) {
+                return Ok(true);
+            }
+            Val::from(self.parser)?;
+        }
+        Ok(false)
+    }
+
+    // Looks up scalar members in any order, scanning the object from its start. The
+    // first member with a key wins; an array or object is an `ExpectedScalar` error.
+    // Afterwards the object is read from its start again.
+    pub fn get_many<const N: usize>(
+        &mut self,
+        keys: [&str; N],
+    ) -> Result<[Option<Scalar<'buf>>; N]> {
+        self.rewind();
+        let mut found = [None; N];
+        let mut remaining = N;
+        while remaining > 0 {
+            let Some((k, _)) = self.advance()? else {
+                break;
+            };
+            let offset = self.parser.offset();
+            let val = Val::from(self.parser)?;
+            for (slot, key) in found.iter_mut().zip(keys) {
+                if slot.is_none() && k.matches(key) {
+                    let kind = ErrorKind::ExpectedScalar;
+                    *slot = Some(val.scalar().ok_or(Error { kind, offset })?);
+                    remaining -= 1;
+                }
+            }
+        }
+        self.rewind();
+        Ok(found)
+    }
+
+    // Moves the parser back to the first member. Nothing else can push containers while
+    // the parser is borrowed, so the bit for this object is still set.
+    fn rewind(&mut self) {
+        self.parser.json.offset = self.start;
+        self.parser.nesting.depth = self.depth;
+    }
+
+    // Skips and validates the remaining members, returning the span of the whole object.
+    pub fn skip_rest(self) -> Result<Range<usize>> {
+        self.parser.ascend_to(self.depth - 1)?;
+        Ok(self.start - 1..self.parser.offset())
+    }
+
+    // Span of the whole object, once `next` has returned `None`.
+    pub fn span(&self) -> Option<Range<usize>> {
+        (self.parser.nesting.depth < self.depth).then(|| self.start - 1..self.parser.offset())
+    }
+
+    // Reads the next key and its span, and moves the parser to the start of its value,
+    // or past the closing bracket.
+    fn advance(&mut self) -> Result<Option<(Key<'buf>, Range<usize>)>> {
+        if self.parser.nesting.depth < self.depth {
+            // Already closed.
+            return Ok(None);
+        }
         if self.parser.offset() == self.start {
             self.parser.skip_ws();
             if let b'}' = self.parser.peek()? {
@@ -366,21 +899,22 @@ impl<'a, 'buf> Object<'a, 'buf> {
                     self.parser.ascend();
                     return Ok(None);
                 }
-                _ => return Err(Error),
+                _ => return Err(self.parser.error(ErrorKind::ExpectedCommaOrClose)),
             }
         }
+        let start = self.parser.offset();
         let key = Key {
             key: self.parser.parse_string()?.as_str(),
         };
+        let span = start..self.parser.offset();
         self.parser.skip_ws();
         if let b':' = self.parser.peek()? {
             self.parser.step()?;
         } else {
-            return Err(Error);
+            return Err(self.parser.error(ErrorKind::ExpectedColon));
         }
         self.parser.skip_ws();
-        let val = Val::from(self.parser)?;
-        Ok(Some((key, val)))
+        Ok(Some((key, span)))
     }
 }
 
@@ -389,20 +923,59 @@ pub struct Key<'a> {
     key: &'a str,
 }
 
-// TODO: tests only
-pub fn key(key: &str) -> Key {
+// Test-only: the key isn't read from any input.
+#[doc(hidden)]
+pub fn key(key: &str) -> Key<'_> {
     Key { key }
 }
 
-// TODO: tests only
+// Test-only: the string isn't read from any input, so it has no source text.
+#[doc(hidden)]
 pub fn string(str: &str) -> String<'_> {
-    String { string: str }
+    String {
+        string: str,
+        quoted: None,
+        escaped: str.contains('\\'),
+        start: 0,
+    }
+}
+
+// The source text of `null`, `true` or `false`.
+#[derive(Debug, Clone, Copy)]
+pub struct Literal<'buf> {
+    literal: &'buf [u8],
+    // Offset of `literal` in the input.
+    start: usize,
+}
+
+// Literals compare by their text, wherever they were read from.
+impl PartialEq for Literal<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.literal == other.literal
+    }
+}
+
+impl Eq for Literal<'_> {}
+
+impl<'buf> Literal<'buf> {
+    pub fn as_bytes(&self) -> &'buf [u8] {
+        self.literal
+    }
+}
+
+// Test-only: the literal isn't read from any input.
+#[doc(hidden)]
+pub fn literal(literal: &str) -> Literal<'_> {
+    Literal {
+        literal: literal.as_bytes(),
+        start: 0,
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Val<'pstate, 'buf> {
-    Null,
-    Boolean(bool),
+    Null(Literal<'buf>),
+    Boolean(bool, Literal<'buf>),
     String(String<'buf>),
     Number(Number<'buf>),
     Array(Array<'pstate, 'buf>),
@@ -412,34 +985,135 @@ pub enum Val<'pstate, 'buf> {
 impl<'a, 'buf> Val<'a, 'buf> {
     pub fn from(parser: &'a mut Parser<'buf>) -> Result<Val<'a, 'buf>> {
         Ok(match parser.peek()? {
-            b'n' => {
-                parser.parse_word(b"null")?;
-                Val::Null
-            }
-            b'f' => {
-                parser.parse_word(b"false")?;
-                Val::Boolean(false)
-            }
-            b't' => {
-                parser.parse_word(b"true")?;
-                Val::Boolean(true)
-            }
+            b'n' => Val::Null(parser.parse_word(b"null")?),
+            b'f' => Val::Boolean(false, parser.parse_word(b"false")?),
+            b't' => Val::Boolean(true, parser.parse_word(b"true")?),
             b'-' | b'0'..=b'9' => Val::Number(parser.parse_number()?),
             b'"' => Val::String(parser.parse_string()?),
             b'{' => Val::Object(parser.parse_object()?),
             b'[' => Val::Array(parser.parse_array()?),
-            _ => return Err(Error),
+            _ => return Err(parser.error(ErrorKind::ExpectedValue)),
+        })
+    }
+
+    // Skips and validates the rest of the value, returning its span. Scalars are
+    // consumed whole when read, so their span is the one recorded then.
+    pub fn skip(self) -> Result<Range<usize>> {
+        match self {
+            Val::Array(a) => a.skip_rest(),
+            Val::Object(o) => o.skip_rest(),
+            // Never `None` for scalars.
+            scalar => Ok(scalar.scalar_span().unwrap_or_default()),
+        }
+    }
+
+    // Skips and validates the rest of the value, returning its source text.
+    pub fn into_raw(self) -> Result<RawJson<'buf>> {
+        let raw = match self {
+            Val::Array(a) => {
+                let json = a.parser.json.buffer;
+                &json[a.skip_rest()?]
+            }
+            Val::Object(o) => {
+                let json = o.parser.json.buffer;
+                &json[o.skip_rest()?]
+            }
+            Val::Null(l) | Val::Boolean(_, l) => l.literal,
+            // Only strings that weren't parsed lack source text.
+            Val::String(s) => s.quoted.ok_or(Error {
+                kind: ErrorKind::ExpectedValue,
+                offset: 0,
+            })?,
+            Val::Number(n) => n.number,
+        };
+        // Never panics: strings are validated as UTF-8, and everything else is ASCII.
+        Ok(RawJson {
+            json: from_utf8(raw).unwrap(),
+        })
+    }
+
+    // `None` for arrays and objects, which end once closed.
+    fn scalar_span(&self) -> Option<Range<usize>> {
+        let (start, len) = match self {
+            Val::Null(l) | Val::Boolean(_, l) => (l.start, l.literal.len()),
+            Val::String(s) => (s.start, s.string.len() + 2),
+            Val::Number(n) => (n.start, n.number.len()),
+            Val::Array(_) | Val::Object(_) => return None,
+        };
+        Some(start..start + len)
+    }
+
+    pub fn scalar(&self) -> Option<Scalar<'buf>> {
+        Some(match self {
+            Val::Null(_) => Scalar::Null,
+            Val::Boolean(b, _) => Scalar::Boolean(*b),
+            Val::String(s) => Scalar::String(*s),
+            Val::Number(n) => Scalar::Number(*n),
+            Val::Array(_) | Val::Object(_) => return None,
         })
     }
 }
 
-/*
-pub enum Seg<'a> {
-    Key(&'a [u8]),
-    Idx(usize),
+// The validated source text of a value, for passing it on unchanged.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RawJson<'buf> {
+    json: &'buf str,
 }
 
-pub fn query<'a, 'b, const N: usize>(json: &'a impl AsRef<[u8]>, path: [Seg<'b>; N]) -> () {
-    ()
+impl<'buf> RawJson<'buf> {
+    pub fn as_bytes(&self) -> &'buf [u8] {
+        self.json.as_bytes()
+    }
+
+    pub fn as_str(&self) -> &'buf str {
+        self.json
+    }
+}
+
+// A value with the position of its source text.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Spanned<'a, 'buf> {
+    pub val: Val<'a, 'buf>,
+    start: usize,
+}
+
+impl<'a, 'buf> Spanned<'a, 'buf> {
+    fn from(parser: &'a mut Parser<'buf>) -> Result<Spanned<'a, 'buf>> {
+        let start = parser.offset();
+        let val = Val::from(parser)?;
+        Ok(Spanned { val, start })
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    // `None` for an array or object that isn't closed yet.
+    pub fn span(&self) -> Option<Range<usize>> {
+        match &self.val {
+            Val::Array(a) => a.span(),
+            Val::Object(o) => o.span(),
+            scalar => scalar.scalar_span(),
+        }
+    }
+
+    // Skips and validates the rest of the value, returning its span, like `Val::skip`.
+    pub fn skip(self) -> Result<Range<usize>> {
+        self.val.skip()
+    }
+
+    // Skips and validates the rest of the value, returning its source text, like
+    // `Val::into_raw`.
+    pub fn into_raw(self) -> Result<RawJson<'buf>> {
+        self.val.into_raw()
+    }
+}
+
+// A `Val` that isn't an array or an object, so it doesn't borrow the parser.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Scalar<'buf> {
+    Null,
+    Boolean(bool),
+    String(String<'buf>),
+    Number(Number<'buf>),
 }
-*/
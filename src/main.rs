@@ -1,4 +1,4 @@
-use tiny_json_parser::{key, parse, string, Error, Val};
+use tiny_json_parser::{key, literal, parse, string, ErrorKind, Val};
 
 fn main() {
     let json0 = br#""#;
@@ -19,16 +19,19 @@ fn main() {
     let json14 = br#"{"x":[{"id": "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx"}], "id": "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx"}"#;
 
     let mut p = parse(json0);
-    assert_eq!(p.value(), Err(Error));
+    assert_eq!(
+        p.value().map_err(|e| e.kind()),
+        Err(ErrorKind::UnexpectedEof)
+    );
 
     let mut p = parse(json1);
-    assert_eq!(p.value(), Ok(Val::Null));
+    assert_eq!(p.value(), Ok(Val::Null(literal("null"))));
 
     let mut p = parse(json2);
-    assert_eq!(p.value().unwrap(), Val::Boolean(true));
+    assert_eq!(p.value().unwrap(), Val::Boolean(true, literal("true")));
 
     let mut p = parse(json3);
-    assert_eq!(p.value().unwrap(), Val::Boolean(false));
+    assert_eq!(p.value().unwrap(), Val::Boolean(false, literal("false")));
 
     let mut p = parse(json4);
     if let Val::Number(num) = p.value().unwrap() {
@@ -54,7 +57,7 @@ fn main() {
 
     match parse(json6).value().unwrap() {
         Val::Array(mut a) => {
-            assert_eq!(a.next().unwrap(), Some(Val::Null));
+            assert_eq!(a.next().unwrap(), Some(Val::Null(literal("null"))));
             assert_eq!(a.next().unwrap(), None);
         }
         _ => panic!(),
@@ -62,8 +65,8 @@ fn main() {
 
     match parse(json7).value().unwrap() {
         Val::Array(mut a) => {
-            assert_eq!(a.next().unwrap(), Some(Val::Null));
-            assert_eq!(a.next().unwrap(), Some(Val::Boolean(true)));
+            assert_eq!(a.next().unwrap(), Some(Val::Null(literal("null"))));
+            assert_eq!(a.next().unwrap(), Some(Val::Boolean(true, literal("true"))));
             assert_eq!(a.next().unwrap(), None);
         }
         _ => panic!(),
@@ -71,9 +74,12 @@ fn main() {
 
     match parse(json8).value() {
         Ok(Val::Array(mut a)) => {
-            assert_eq!(a.next().unwrap(), Some(Val::Null));
-            assert_eq!(a.next().unwrap(), Some(Val::Boolean(true)));
-            assert_eq!(a.next().unwrap(), Some(Val::Boolean(false)));
+            assert_eq!(a.next().unwrap(), Some(Val::Null(literal("null"))));
+            assert_eq!(a.next().unwrap(), Some(Val::Boolean(true, literal("true"))));
+            assert_eq!(
+                a.next().unwrap(),
+                Some(Val::Boolean(false, literal("false")))
+            );
             assert_eq!(a.next().unwrap(), None);
         }
         _ => panic!(),
@@ -81,13 +87,16 @@ fn main() {
 
     match parse(json9).value() {
         Ok(Val::Array(mut a)) => {
-            assert_eq!(a.next().unwrap(), Some(Val::Null));
+            assert_eq!(a.next().unwrap(), Some(Val::Null(literal("null"))));
             if let Ok(Some(Val::Array(mut b))) = a.next() {
                 assert_eq!(b.next().unwrap(), None);
             } else {
                 panic!();
             };
-            assert_eq!(a.next().unwrap(), Some(Val::Boolean(false)));
+            assert_eq!(
+                a.next().unwrap(),
+                Some(Val::Boolean(false, literal("false")))
+            );
             assert_eq!(a.next().unwrap(), None);
         }
         _ => panic!(),
@@ -95,11 +104,14 @@ fn main() {
 
     match parse(json9).value() {
         Ok(Val::Array(mut a)) => {
-            assert_eq!(a.next().unwrap(), Some(Val::Null));
+            assert_eq!(a.next().unwrap(), Some(Val::Null(literal("null"))));
             let Ok(Some(Val::Array(_))) = a.next() else {
                 panic!();
             };
-            assert_eq!(a.next().unwrap(), Some(Val::Boolean(false)));
+            assert_eq!(
+                a.next().unwrap(),
+                Some(Val::Boolean(false, literal("false")))
+            );
             assert_eq!(a.next().unwrap(), None);
         }
         _ => panic!(),
@@ -107,14 +119,17 @@ fn main() {
 
     match parse(json10).value() {
         Ok(Val::Array(mut a)) => {
-            assert_eq!(a.next().unwrap(), Some(Val::Null));
+            assert_eq!(a.next().unwrap(), Some(Val::Null(literal("null"))));
             if let Ok(Some(Val::Array(mut b))) = a.next() {
-                assert_eq!(b.next().unwrap(), Some(Val::Boolean(true)));
+                assert_eq!(b.next().unwrap(), Some(Val::Boolean(true, literal("true"))));
                 assert_eq!(b.next().unwrap(), None);
             } else {
                 panic!();
             };
-            assert_eq!(a.next().unwrap(), Some(Val::Boolean(false)));
+            assert_eq!(
+                a.next().unwrap(),
+                Some(Val::Boolean(false, literal("false")))
+            );
             assert_eq!(a.next().unwrap(), None);
         }
         _ => panic!(),
@@ -122,11 +137,14 @@ fn main() {
 
     match parse(json10).value() {
         Ok(Val::Array(mut a)) => {
-            assert_eq!(a.next().unwrap(), Some(Val::Null));
+            assert_eq!(a.next().unwrap(), Some(Val::Null(literal("null"))));
             let Ok(Some(Val::Array(_))) = a.next() else {
                 panic!();
             };
-            assert_eq!(a.next().unwrap(), Some(Val::Boolean(false)));
+            assert_eq!(
+                a.next().unwrap(),
+                Some(Val::Boolean(false, literal("false")))
+            );
             assert_eq!(a.next().unwrap(), None);
         }
         _ => panic!(),
@@ -134,42 +152,54 @@ fn main() {
 
     match parse(json11).value() {
         Ok(Val::Array(mut a)) => {
-            assert_eq!(a.next().unwrap(), Some(Val::Null));
+            assert_eq!(a.next().unwrap(), Some(Val::Null(literal("null"))));
             if let Ok(Some(Val::Array(mut b))) = a.next() {
-                assert_eq!(b.next().unwrap(), Some(Val::Boolean(true)));
-                assert_eq!(b.next().unwrap(), Some(Val::Boolean(true)));
+                assert_eq!(b.next().unwrap(), Some(Val::Boolean(true, literal("true"))));
+                assert_eq!(b.next().unwrap(), Some(Val::Boolean(true, literal("true"))));
                 assert_eq!(b.next().unwrap(), None);
             } else {
                 panic!();
             };
-            assert_eq!(a.next().unwrap(), Some(Val::Boolean(false)));
+            assert_eq!(
+                a.next().unwrap(),
+                Some(Val::Boolean(false, literal("false")))
+            );
         }
         _ => panic!(),
     };
 
     match parse(json11).value() {
         Ok(Val::Array(mut a)) => {
-            assert_eq!(a.next(), Ok(Some(Val::Null)));
+            assert_eq!(a.next(), Ok(Some(Val::Null(literal("null")))));
             let Ok(Some(Val::Array(_))) = a.next() else {
                 panic!();
             };
-            assert_eq!(a.next(), Ok(Some(Val::Boolean(false))));
+            assert_eq!(a.next(), Ok(Some(Val::Boolean(false, literal("false")))));
         }
         _ => panic!(),
     };
 
     match parse(json12).value() {
         Ok(Val::Object(mut o)) => {
-            assert_eq!(o.next(), Ok(Some((key("a"), Val::Null))));
+            assert_eq!(o.next(), Ok(Some((key("a"), Val::Null(literal("null"))))));
             if let Ok(Some((k, Val::Object(mut p)))) = o.next() {
                 assert_eq!(k, key("b"));
-                assert_eq!(p.next().unwrap(), Some((key("c"), Val::Boolean(true))));
-                assert_eq!(p.next().unwrap(), Some((key("d"), Val::Null)));
+                assert_eq!(
+                    p.next().unwrap(),
+                    Some((key("c"), Val::Boolean(true, literal("true"))))
+                );
+                assert_eq!(
+                    p.next().unwrap(),
+                    Some((key("d"), Val::Null(literal("null"))))
+                );
                 assert_eq!(p.next().unwrap(), None);
             } else {
                 panic!();
             };
-            assert_eq!(o.next(), Ok(Some((key("e"), Val::Boolean(false)))));
+            assert_eq!(
+                o.next(),
+                Ok(Some((key("e"), Val::Boolean(false, literal("false")))))
+            );
         }
         _ => panic!(),
     };
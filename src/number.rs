@@ -0,0 +1,304 @@
+use core::fmt::{self, Display};
+
+use crate::Number;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NumberError {
+    NotAnInteger,
+    Overflow,
+    PrecisionLost,
+}
+
+impl Display for NumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            NumberError::NotAnInteger => "number is not an integer",
+            NumberError::Overflow => "number is out of range",
+            NumberError::PrecisionLost => "number can't be represented exactly",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NumberError {}
+
+// How `Number::to_fixed_with` treats digits beyond the requested scale.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    // Fail with `NumberError::PrecisionLost` unless the dropped digits are all zeros.
+    Exact,
+    TowardZero,
+    HalfAwayFromZero,
+    HalfEven,
+}
+
+fn digit(b: u8) -> u128 {
+    (b - b'0') as u128
+}
+
+fn signed((negative, magnitude): (bool, u128)) -> Result<i128, NumberError> {
+    match (negative, magnitude) {
+        (false, magnitude) => magnitude.try_into().map_err(|_| NumberError::Overflow),
+        (true, magnitude) if magnitude <= i128::MIN.unsigned_abs() => {
+            Ok(0i128.wrapping_sub_unsigned(magnitude))
+        }
+        (true, _) => Err(NumberError::Overflow),
+    }
+}
+
+impl<'buf> Number<'buf> {
+    // Splits the number into sign, integer digits, fraction digits and exponent digits
+    // (including its sign). Relies on INVARIANT A and the grammar checked by `parse_number`.
+    fn split(&self) -> (bool, &'buf [u8], &'buf [u8], &'buf [u8]) {
+        let (negative, rest) = match self.number {
+            [b'-', rest @ ..] => (true, rest),
+            rest => (false, rest),
+        };
+        let int_len = rest
+            .iter()
+            .position(|&b| !b.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (int, rest) = rest.split_at(int_len);
+        let (frac, exp) = match rest {
+            [b'.', rest @ ..] => {
+                let frac_len = rest
+                    .iter()
+                    .position(|&b| !b.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                rest.split_at(frac_len)
+            }
+            rest => (&rest[..0], rest),
+        };
+        let exp = match exp {
+            [b'e' | b'E', exp @ ..] => exp,
+            exp => exp,
+        };
+        (negative, int, frac, exp)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        !self.has_fraction() && !self.has_exponent()
+    }
+
+    pub fn has_fraction(&self) -> bool {
+        self.number.contains(&b'.')
+    }
+
+    pub fn has_exponent(&self) -> bool {
+        self.number.iter().any(|&b| b == b'e' || b == b'E')
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.number.first() == Some(&b'-')
+    }
+
+    // `-0`, `-0.0`, `-0e5` etc.
+    pub fn is_negative_zero(&self) -> bool {
+        self.is_negative() && self.is_zero()
+    }
+
+    // -1 or 1. Zero is signed as well, see `is_negative_zero`.
+    pub fn sign(&self) -> i8 {
+        if self.is_negative() {
+            -1
+        } else {
+            1
+        }
+    }
+
+    pub fn integer_digits(&self) -> &'buf [u8] {
+        self.split().1
+    }
+
+    // Empty if the number has no fraction.
+    pub fn fraction_digits(&self) -> &'buf [u8] {
+        self.split().2
+    }
+
+    // Zero if the number has no exponent.
+    pub fn exponent(&self) -> Result<i32, NumberError> {
+        self.exponent_i64()
+            .try_into()
+            .map_err(|_| NumberError::Overflow)
+    }
+
+    // Saturates, so that absurdly long exponents don't overflow.
+    fn exponent_i64(&self) -> i64 {
+        let (_, _, _, exp) = self.split();
+        let (negative, digits) = match exp {
+            [b'-', digits @ ..] => (true, digits),
+            [b'+', digits @ ..] => (false, digits),
+            digits => (false, digits),
+        };
+        let magnitude = digits.iter().fold(0i64, |acc, &b| {
+            acc.saturating_mul(10).saturating_add((b - b'0') as i64)
+        });
+        if negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        let (_, int, frac, _) = self.split();
+        int.iter().chain(frac).all(|&b| b == b'0')
+    }
+
+    // Exact integral value as sign and magnitude.
+    fn integer(&self) -> Result<(bool, u128), NumberError> {
+        self.scaled(0, Rounding::Exact)
+    }
+
+    // Value multiplied by 10^`scale` and rounded to an integer, as sign and magnitude.
+    // `Rounding::Exact` fails with `NotAnInteger`.
+    fn scaled(&self, scale: i64, rounding: Rounding) -> Result<(bool, u128), NumberError> {
+        let (negative, int, frac, _) = self.split();
+        let digits = int.iter().chain(frac);
+        let exp = self
+            .exponent_i64()
+            .saturating_sub(frac.len() as i64)
+            .saturating_add(scale);
+        let n_digits = int.len() + frac.len();
+
+        // With a negative exponent, the last digits are dropped. If there are less
+        // digits than that, the first dropped one is an implicit leading zero.
+        let (keep, first_dropped, rest) = if exp < 0 {
+            let drop = exp.unsigned_abs().try_into().unwrap_or(usize::MAX);
+            match n_digits.checked_sub(drop) {
+                Some(keep) => (keep, digits.clone().nth(keep).copied(), keep + 1),
+                None => (0, Some(b'0'), 0),
+            }
+        } else {
+            (n_digits, None, n_digits)
+        };
+
+        let mut magnitude: u128 = 0;
+        for &b in digits.clone().take(keep) {
+            magnitude = magnitude
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(digit(b)))
+                .ok_or(NumberError::Overflow)?;
+        }
+
+        if let Some(first_dropped) = first_dropped {
+            let rest_nonzero = digits.skip(rest).any(|&b| b != b'0');
+            let round_up = match (rounding, first_dropped) {
+                (Rounding::Exact, b'0') if !rest_nonzero => false,
+                (Rounding::Exact, _) => return Err(NumberError::NotAnInteger),
+                (Rounding::TowardZero, _) => false,
+                (Rounding::HalfAwayFromZero, d) => d >= b'5',
+                (Rounding::HalfEven, b'5') => rest_nonzero || magnitude % 2 == 1,
+                (Rounding::HalfEven, d) => d > b'5',
+            };
+            if round_up {
+                magnitude = magnitude.checked_add(1).ok_or(NumberError::Overflow)?;
+            }
+        } else if magnitude != 0 {
+            for _ in 0..exp {
+                magnitude = magnitude.checked_mul(10).ok_or(NumberError::Overflow)?;
+            }
+        }
+        Ok((negative, magnitude))
+    }
+
+    fn as_u128(&self) -> Result<u128, NumberError> {
+        match self.integer()? {
+            (true, magnitude) if magnitude != 0 => Err(NumberError::Overflow),
+            (_, magnitude) => Ok(magnitude),
+        }
+    }
+
+    pub fn as_u64(&self) -> Result<u64, NumberError> {
+        self.as_u128()?
+            .try_into()
+            .map_err(|_| NumberError::Overflow)
+    }
+
+    pub fn as_u32(&self) -> Result<u32, NumberError> {
+        self.as_u128()?
+            .try_into()
+            .map_err(|_| NumberError::Overflow)
+    }
+
+    pub fn as_i128(&self) -> Result<i128, NumberError> {
+        signed(self.integer()?)
+    }
+
+    pub fn as_i64(&self) -> Result<i64, NumberError> {
+        self.as_i128()?
+            .try_into()
+            .map_err(|_| NumberError::Overflow)
+    }
+
+    pub fn as_i32(&self) -> Result<i32, NumberError> {
+        self.as_i128()?
+            .try_into()
+            .map_err(|_| NumberError::Overflow)
+    }
+
+    // The value multiplied by 10^`SCALE`, failing with `NumberError::PrecisionLost`
+    // if it has more significant fraction digits than that.
+    pub fn to_fixed<const SCALE: u32>(&self) -> Result<i64, NumberError> {
+        self.to_fixed_with(SCALE, Rounding::Exact)
+    }
+
+    pub fn to_fixed_with(&self, scale: u32, rounding: Rounding) -> Result<i64, NumberError> {
+        let scaled = self.scaled(scale.into(), rounding).map_err(|e| match e {
+            NumberError::NotAnInteger => NumberError::PrecisionLost,
+            e => e,
+        })?;
+        signed(scaled)?
+            .try_into()
+            .map_err(|_| NumberError::Overflow)
+    }
+
+    // Fails with `Overflow` if the value rounds to infinity, and with `PrecisionLost` if
+    // a non-zero value rounds to zero, or an integer literal can't be represented exactly.
+    pub fn as_f64(&self) -> Result<f64, NumberError> {
+        // The JSON number grammar is a subset of the one accepted by `f64::from_str`.
+        let float: f64 = self
+            .as_str()
+            .parse()
+            .map_err(|_| NumberError::PrecisionLost)?;
+        self.check_float(float.is_infinite(), float == 0.0, |m| {
+            float.abs() as u128 == m
+        })?;
+        Ok(float)
+    }
+
+    // Same rules as `as_f64`.
+    pub fn as_f32(&self) -> Result<f32, NumberError> {
+        let float: f32 = self
+            .as_str()
+            .parse()
+            .map_err(|_| NumberError::PrecisionLost)?;
+        self.check_float(float.is_infinite(), float == 0.0, |m| {
+            float.abs() as u128 == m
+        })?;
+        Ok(float)
+    }
+
+    fn check_float(
+        &self,
+        infinite: bool,
+        zero: bool,
+        exact: impl FnOnce(u128) -> bool,
+    ) -> Result<(), NumberError> {
+        if infinite {
+            return Err(NumberError::Overflow);
+        }
+        if zero && !self.is_zero() {
+            return Err(NumberError::PrecisionLost);
+        }
+        let (_, _, frac, exp) = self.split();
+        if frac.is_empty() && exp.is_empty() {
+            match self.integer() {
+                Ok((_, magnitude)) if exact(magnitude) => (),
+                _ => return Err(NumberError::PrecisionLost),
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use core::iter::from_fn;
+
+use crate::{Error, ErrorKind, Key, Parser, Result, Val};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Seg<'a> {
+    // Matches an object member with this (unescaped) key.
+    Key(&'a [u8]),
+    // Matches an array element.
+    Idx(usize),
+}
+
+impl<'a> From<&'a str> for Seg<'a> {
+    fn from(key: &'a str) -> Self {
+        Seg::Key(key.as_bytes())
+    }
+}
+
+impl<'a> From<&'a [u8]> for Seg<'a> {
+    fn from(key: &'a [u8]) -> Self {
+        Seg::Key(key)
+    }
+}
+
+impl From<usize> for Seg<'_> {
+    fn from(idx: usize) -> Self {
+        Seg::Idx(idx)
+    }
+}
+
+// Builds a `[Seg; N]` path from keys and indices: `json_path!("devices", 3, "name")`.
+#[macro_export]
+macro_rules! json_path {
+    ($($seg:expr),* $(,)?) => {
+        [$($crate::Seg::from($seg)),*]
+    };
+}
+
+pub(crate) trait Segment {
+    fn matches_key(&self, key: &Key<'_>) -> bool;
+    fn index(&self) -> Option<usize>;
+}
+
+impl Segment for Seg<'_> {
+    fn matches_key(&self, key: &Key<'_>) -> bool {
+        match self {
+            Seg::Key(expected) => key.matches_bytes(expected.iter().copied()),
+            Seg::Idx(_) => false,
+        }
+    }
+
+    fn index(&self) -> Option<usize> {
+        match self {
+            Seg::Key(_) => None,
+            Seg::Idx(idx) => Some(*idx),
+        }
+    }
+}
+
+// A JSON pointer reference token checked by `Parser::pointer`.
+struct Token<'a>(&'a str);
+
+impl Segment for Token<'_> {
+    fn matches_key(&self, key: &Key<'_>) -> bool {
+        key.matches_bytes(token_bytes(self.0))
+    }
+
+    fn index(&self) -> Option<usize> {
+        array_index(self.0)
+    }
+}
+
+// Unescapes `~0` and `~1` in a reference token checked by `Parser::pointer`.
+fn token_bytes(token: &str) -> impl Iterator<Item = u8> + '_ {
+    let mut bytes = token.bytes();
+    from_fn(move || match bytes.next()? {
+        b'~' => match bytes.next() {
+            Some(b'0') => Some(b'~'),
+            _ => Some(b'/'),
+        },
+        b => Some(b),
+    })
+}
+
+fn array_index(token: &str) -> Option<usize> {
+    match token.as_bytes() {
+        [b'0'] => Some(0),
+        [b'1'..=b'9', rest @ ..] if rest.iter().all(u8::is_ascii_digit) => token.parse().ok(),
+        _ => None,
+    }
+}
+
+impl<'buf> Parser<'buf> {
+    // Looks up an RFC 6901 JSON pointer, such as `/devices/3/name`, in the value at the
+    // current position, skipping everything before the match. An invalid pointer fails
+    // with `InvalidPointer` at the offset of that value.
+    pub fn pointer<'a>(&'a mut self, pointer: &str) -> Result<Option<Val<'a, 'buf>>> {
+        let mut tokens = pointer.split('/');
+        let valid = tokens.next() == Some("")
+            && tokens.clone().all(|token| {
+                let mut escapes = token.split('~').skip(1);
+                escapes.all(|rest| rest.starts_with(['0', '1']))
+            });
+        if !valid {
+            // The offset of the value, found without moving the parser.
+            let rest = &self.json.buffer[self.offset()..];
+            let ws = rest
+                .iter()
+                .take_while(|b| matches!(b, b' ' | b'\r' | b'\n' | b'\t'))
+                .count();
+            return Err(Error {
+                kind: ErrorKind::InvalidPointer,
+                offset: self.offset() + ws,
+            });
+        }
+
+        self.find(tokens.map(Token))
+    }
+
+    // Looks up `path` in the value at the current position, like `pointer`.
+    pub fn query<'a, const N: usize>(
+        &'a mut self,
+        path: [Seg<'_>; N],
+    ) -> Result<Option<Val<'a, 'buf>>> {
+        self.find(path)
+    }
+
+    // Stops as soon as a segment can't match.
+    fn find<'a>(
+        &'a mut self,
+        path: impl IntoIterator<Item = impl Segment>,
+    ) -> Result<Option<Val<'a, 'buf>>> {
+        self.skip_ws();
+        let mut parser = self;
+        for segment in path {
+            parser = match Val::from(parser)? {
+                Val::Object(mut o) => {
+                    if !o.seek(|key| segment.matches_key(key))? {
+                        return Ok(None);
+                    }
+                    o.parser
+                }
+                Val::Array(mut a) => {
+                    let Some(index) = segment.index() else {
+                        return Ok(None);
+                    };
+                    for _ in 0..index {
+                        if !a.advance()? {
+                            return Ok(None);
+                        }
+                        Val::from(a.parser)?;
+                    }
+                    if !a.advance()? {
+                        return Ok(None);
+                    }
+                    a.parser
+                }
+                _ => return Ok(None),
+            };
+        }
+        Ok(Some(Val::from(parser)?))
+    }
+}
@@ -0,0 +1,225 @@
+use core::str::from_utf8;
+
+use crate::{Error, ErrorKind, Key, Result, String};
+
+fn hex4(digits: &[u8]) -> Option<u32> {
+    let digits = digits.get(..4)?;
+    digits.iter().try_fold(0, |acc, &b| {
+        let digit = match b {
+            b'0'..=b'9' => b - b'0',
+            b'a'..=b'f' => b - b'a' + 10,
+            b'A'..=b'F' => b - b'A' + 10,
+            _ => return None,
+        };
+        Some(acc << 4 | digit as u32)
+    })
+}
+
+// Decodes the escape sequence at the start of `escape`, which begins with the backslash.
+// Returns the decoded character, or the error, and the length of the sequence.
+// Strings from `parse_string` only contain valid escapes, but lone surrogates are allowed.
+pub(crate) fn unescape(escape: &[u8]) -> (core::result::Result<char, ErrorKind>, usize) {
+    let c = match escape.get(1) {
+        Some(b'"') => '"',
+        Some(b'\\') => '\\',
+        Some(b'/') => '/',
+        Some(b'b') => '\u{8}',
+        Some(b'f') => '\u{c}',
+        Some(b'n') => '\n',
+        Some(b'r') => '\r',
+        Some(b't') => '\t',
+        Some(b'u') => {
+            let Some(first) = hex4(&escape[2..]) else {
+                return (Err(ErrorKind::InvalidEscape), escape.len().min(2));
+            };
+            return match first {
+                0xD800..=0xDBFF => match (escape.get(6..8), escape.get(8..).and_then(hex4)) {
+                    (Some(b"\\u"), Some(second @ 0xDC00..=0xDFFF)) => {
+                        let c = 0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00);
+                        // Never fails: the result is in 0x10000..=0x10FFFF.
+                        (char::from_u32(c).ok_or(ErrorKind::LoneSurrogate), 12)
+                    }
+                    _ => (Err(ErrorKind::LoneSurrogate), 6),
+                },
+                c => (char::from_u32(c).ok_or(ErrorKind::LoneSurrogate), 6),
+            };
+        }
+        _ => return (Err(ErrorKind::InvalidEscape), escape.len().min(2)),
+    };
+    (Ok(c), 2)
+}
+
+impl<'buf> String<'buf> {
+    // Decodes the contents lazily. Lone surrogates are replaced with U+FFFD REPLACEMENT
+    // CHARACTER.
+    pub fn chars(&self) -> Chars<'buf> {
+        Chars { rest: self.string }
+    }
+
+    // UTF-8 encoding of `chars`.
+    pub fn decoded_bytes(&self) -> DecodedBytes<'buf> {
+        DecodedBytes {
+            rest: self.as_bytes(),
+            pending: [0; 4],
+            pending_start: 0,
+            pending_end: 0,
+        }
+    }
+
+    // Length of the contents in bytes after decoding the escapes. Lone surrogates
+    // count as U+FFFD REPLACEMENT CHARACTER.
+    pub fn unescaped_len(&self) -> usize {
+        if !self.escaped {
+            return self.string.len();
+        }
+        let mut bytes = self.as_bytes();
+        let mut len = 0;
+        while let Some(escape) = bytes.iter().position(|&b| b == b'\\') {
+            let (c, escape_len) = unescape(&bytes[escape..]);
+            len += escape + c.unwrap_or(char::REPLACEMENT_CHARACTER).len_utf8();
+            bytes = &bytes[escape + escape_len..];
+        }
+        len + bytes.len()
+    }
+
+    // Decodes the escapes into `out`. Lone surrogates are replaced like in `chars`; parse
+    // with `ParserConfig::with_strict_surrogates` to reject them instead. Fails with
+    // `BufferTooSmall` if `out` is shorter than `unescaped_len`, which is then the
+    // error's offset.
+    pub fn unescape_into<'o>(&self, out: &'o mut [u8]) -> Result<&'o str> {
+        if out.len() < self.unescaped_len() {
+            return Err(Error {
+                kind: ErrorKind::BufferTooSmall,
+                offset: self.unescaped_len(),
+            });
+        }
+        let mut bytes = self.as_bytes();
+        let mut len = 0;
+        loop {
+            let plain = bytes
+                .iter()
+                .position(|&b| b == b'\\')
+                .unwrap_or(bytes.len());
+            // Never panics: `out` has room for `unescaped_len` bytes.
+            out[len..len + plain].copy_from_slice(&bytes[..plain]);
+            len += plain;
+            bytes = &bytes[plain..];
+            if bytes.is_empty() {
+                break;
+            }
+
+            let (c, escape_len) = unescape(bytes);
+            let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+            c.encode_utf8(&mut out[len..len + c.len_utf8()]);
+            len += c.len_utf8();
+            bytes = &bytes[escape_len..];
+        }
+        // Never fails: valid UTF-8 was copied, and escapes were encoded as UTF-8.
+        from_utf8(&out[..len]).map_err(|_| Error {
+            kind: ErrorKind::InvalidUtf8,
+            offset: 0,
+        })
+    }
+}
+
+impl Key<'_> {
+    // Compares the decoded key with `expected`, without allocating. Keys with lone
+    // surrogates don't match anything.
+    pub fn matches(&self, expected: &str) -> bool {
+        self.matches_bytes(expected.bytes())
+    }
+
+    pub(crate) fn matches_bytes(&self, mut expected: impl Iterator<Item = u8>) -> bool {
+        let mut key = self.key.as_bytes();
+        while let Some((&b, rest)) = key.split_first() {
+            if b == b'\\' {
+                let (c, len) = unescape(key);
+                let Ok(c) = c else {
+                    return false;
+                };
+                let mut encoded = [0; 4];
+                let mut encoded = c.encode_utf8(&mut encoded).bytes();
+                if !encoded.all(|b| expected.next() == Some(b)) {
+                    return false;
+                }
+                key = &key[len..];
+            } else {
+                if expected.next() != Some(b) {
+                    return false;
+                }
+                key = rest;
+            }
+        }
+        expected.next().is_none()
+    }
+}
+
+impl PartialEq<str> for Key<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<&str> for Key<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Chars<'buf> {
+    rest: &'buf str,
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.rest.starts_with('\\') {
+            let (c, len) = unescape(self.rest.as_bytes());
+            // Escapes are ASCII, so `len` is at a char boundary.
+            self.rest = self.rest.get(len..).unwrap_or_default();
+            Some(c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        } else {
+            let mut chars = self.rest.chars();
+            let c = chars.next();
+            self.rest = chars.as_str();
+            c
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DecodedBytes<'buf> {
+    rest: &'buf [u8],
+    // The encoding of the last decoded escape, not yet returned.
+    pending: [u8; 4],
+    pending_start: usize,
+    pending_end: usize,
+}
+
+impl Iterator for DecodedBytes<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.pending_start == self.pending_end {
+            match self.rest {
+                [] => return None,
+                [b'\\', ..] => {
+                    let (c, len) = unescape(self.rest);
+                    let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+                    self.pending_start = 0;
+                    self.pending_end = c.encode_utf8(&mut self.pending).len();
+                    self.rest = self.rest.get(len..).unwrap_or_default();
+                }
+                [b, rest @ ..] => {
+                    self.rest = rest;
+                    return Some(*b);
+                }
+            }
+        }
+        let b = self.pending.get(self.pending_start).copied();
+        self.pending_start += 1;
+        b
+    }
+}
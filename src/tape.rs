@@ -0,0 +1,333 @@
+use core::{ops::Range, str::from_utf8};
+
+use crate::{
+    pointer::Segment, Error, ErrorKind, Event, Key, Number, Parser, Result, Scalar, Seg, String,
+};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EntryKind {
+    #[default]
+    Null,
+    False,
+    True,
+    Number,
+    String,
+    // An object member's key, followed by the entry of its value.
+    Key,
+    Array,
+    Object,
+}
+
+// One value or key of a document, recorded by `Parser::tape`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct TapeEntry {
+    kind: EntryKind,
+    start: usize,
+    end: usize,
+    // Index of the enclosing array or object; the root is its own parent.
+    parent: usize,
+    // Index of the first entry after this subtree.
+    next: usize,
+    // Number of elements or members.
+    len: usize,
+}
+
+// Storage filled by `Parser::build`, one slot per value and key in document order.
+// `build` only counts and closes containers it pushed, but implementations still
+// check their indexes instead of panicking.
+pub(crate) trait Slots<'buf> {
+    // Stores `event`, a value or key spanning `span`, at `index`. `parent` is the
+    // enclosing container; the root is its own parent. Returns `false` if there is no room.
+    fn push(&mut self, index: usize, parent: usize, event: Event<'buf>, span: Range<usize>)
+        -> bool;
+
+    // Adds an element or member to the container at `index`.
+    fn count(&mut self, index: usize);
+
+    // Closes the container at `index`, which ends at byte `end` and is followed by slot
+    // `next`. Returns the container's parent, or `index` if there is no container there.
+    fn close(&mut self, index: usize, end: usize, next: usize) -> usize;
+}
+
+impl<'buf> Parser<'buf> {
+    // Indexes the whole document into `tape`, one entry per value and key, and checks
+    // that nothing follows it. Fails with `TapeFull` if `tape` is too short, and with
+    // `NotAtStart` if part of the document was already read.
+    pub fn tape<'t>(&mut self, tape: &'t mut [TapeEntry]) -> Result<Document<'t, 'buf>> {
+        let len = self.build(tape, ErrorKind::TapeFull)?;
+        Ok(Document {
+            json: self.json.buffer,
+            tape: &tape[..len],
+        })
+    }
+
+    // Stores the whole document into `slots` and checks that nothing follows it. Returns
+    // the number of slots used, or `full` at the first value that doesn't fit.
+    pub(crate) fn build(
+        &mut self,
+        slots: &mut (impl Slots<'buf> + ?Sized),
+        full: ErrorKind,
+    ) -> Result<usize> {
+        // Containers opened before this call were never stored, so they can't be closed.
+        if self.offset() > 0 {
+            return Err(self.error(ErrorKind::NotAtStart));
+        }
+        let mut len = 0;
+        // The innermost open container.
+        let mut parent = 0;
+        loop {
+            let in_array = self.nesting.in_object() == Some(false);
+            let Some(event) = self.next_event()? else {
+                break;
+            };
+            let span = self.event_span(&event);
+            match event {
+                Event::EndObject | Event::EndArray => {
+                    parent = slots.close(parent, span.end, len);
+                    continue;
+                }
+                // Object members are counted by their keys.
+                Event::Key(_) => slots.count(parent),
+                _ if in_array => slots.count(parent),
+                _ => (),
+            }
+            let start = span.start;
+            if !slots.push(len, parent, event, span) {
+                return Err(Error {
+                    kind: full,
+                    offset: start,
+                });
+            }
+            if let Event::StartObject | Event::StartArray = event {
+                parent = len;
+            }
+            len += 1;
+        }
+        self.finalize()?;
+        Ok(len)
+    }
+}
+
+impl<'buf> Slots<'buf> for [TapeEntry] {
+    fn push(
+        &mut self,
+        index: usize,
+        parent: usize,
+        event: Event<'buf>,
+        span: Range<usize>,
+    ) -> bool {
+        let kind = match event {
+            Event::StartObject => EntryKind::Object,
+            Event::StartArray => EntryKind::Array,
+            Event::Key(_) => EntryKind::Key,
+            Event::String(_) => EntryKind::String,
+            Event::Number(_) => EntryKind::Number,
+            Event::Bool(false) => EntryKind::False,
+            Event::Bool(true) => EntryKind::True,
+            Event::Null | Event::EndObject | Event::EndArray => EntryKind::Null,
+        };
+        let Some(entry) = self.get_mut(index) else {
+            return false;
+        };
+        *entry = TapeEntry {
+            kind,
+            start: span.start,
+            end: span.end,
+            parent,
+            next: index + 1,
+            len: 0,
+        };
+        true
+    }
+
+    fn count(&mut self, index: usize) {
+        if let Some(entry) = self.get_mut(index) {
+            entry.len += 1;
+        }
+    }
+
+    fn close(&mut self, index: usize, end: usize, next: usize) -> usize {
+        let Some(entry) = self.get_mut(index) else {
+            return index;
+        };
+        entry.end = end;
+        entry.next = next;
+        entry.parent
+    }
+}
+
+// Random access to a document indexed by `Parser::tape`.
+#[derive(Debug, Copy, Clone)]
+pub struct Document<'t, 'buf> {
+    json: &'buf [u8],
+    tape: &'t [TapeEntry],
+}
+
+impl<'t, 'buf> Document<'t, 'buf> {
+    pub fn root(&self) -> TapeRef<'t, 'buf> {
+        TapeRef {
+            doc: *self,
+            index: 0,
+        }
+    }
+
+    // Number of tape entries used.
+    pub fn len(&self) -> usize {
+        self.tape.len()
+    }
+
+    // Never true for a complete document.
+    pub fn is_empty(&self) -> bool {
+        self.tape.is_empty()
+    }
+
+    pub fn entry(&self, index: usize) -> Option<TapeRef<'t, 'buf>> {
+        (index < self.tape.len()).then_some(TapeRef { doc: *self, index })
+    }
+}
+
+// A value or key on the tape. Moving between entries is O(1), except for `at` and `get`,
+// which hop over siblings.
+#[derive(Debug, Copy, Clone)]
+pub struct TapeRef<'t, 'buf> {
+    doc: Document<'t, 'buf>,
+    index: usize,
+}
+
+impl<'t, 'buf> TapeRef<'t, 'buf> {
+    fn entry(&self) -> &'t TapeEntry {
+        // Never panics: `Document::root` is only built for a non-empty tape, and every
+        // other `TapeRef` comes from `Document::entry` or an index checked against the
+        // `next` of its parent, which is at most the tape length.
+        &self.doc.tape[self.index]
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn kind(&self) -> EntryKind {
+        self.entry().kind
+    }
+
+    // For keys, the span covers the quoted key only.
+    pub fn span(&self) -> Range<usize> {
+        self.entry().start..self.entry().end
+    }
+
+    pub fn as_bytes(&self) -> &'buf [u8] {
+        &self.doc.json[self.span()]
+    }
+
+    // Number of elements or members, zero for scalars and keys.
+    pub fn len(&self) -> usize {
+        self.entry().len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // The enclosing array or object. The parent of a member value is its object.
+    pub fn parent(&self) -> Option<TapeRef<'t, 'buf>> {
+        match self.entry().parent {
+            parent if parent == self.index => None,
+            parent => self.doc.entry(parent),
+        }
+    }
+
+    // The next element, key, or (for a key) its value, skipping this subtree.
+    pub fn next_sibling(&self) -> Option<TapeRef<'t, 'buf>> {
+        let parent = self.parent()?;
+        let next = self.entry().next;
+        (next < parent.entry().next).then_some(TapeRef {
+            doc: self.doc,
+            index: next,
+        })
+    }
+
+    // The first element, or the first key of an object.
+    pub fn first_child(&self) -> Option<TapeRef<'t, 'buf>> {
+        match self.kind() {
+            EntryKind::Array | EntryKind::Object if !self.is_empty() => {
+                self.doc.entry(self.index + 1)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn at(&self, index: usize) -> Option<TapeRef<'t, 'buf>> {
+        if self.kind() != EntryKind::Array {
+            return None;
+        }
+        let mut child = self.first_child();
+        for _ in 0..index {
+            child = child?.next_sibling();
+        }
+        child
+    }
+
+    // The value of the first member with `key`, compared with the decoded keys.
+    pub fn get(&self, key: &str) -> Option<TapeRef<'t, 'buf>> {
+        self.find_member(|k| k.matches(key))
+    }
+
+    pub fn query(&self, path: &[Seg<'_>]) -> Option<TapeRef<'t, 'buf>> {
+        path.iter().try_fold(*self, |node, seg| match seg.index() {
+            Some(index) => node.at(index),
+            None => node.find_member(|k| seg.matches_key(k)),
+        })
+    }
+
+    fn find_member(&self, matches: impl Fn(&Key<'buf>) -> bool) -> Option<TapeRef<'t, 'buf>> {
+        if self.kind() != EntryKind::Object {
+            return None;
+        }
+        let mut key = self.first_child();
+        while let Some(k) = key {
+            let value = k.next_sibling()?;
+            if matches(&k.key()?) {
+                return Some(value);
+            }
+            key = value.next_sibling();
+        }
+        None
+    }
+
+    pub fn key(&self) -> Option<Key<'buf>> {
+        match self.kind() {
+            EntryKind::Key => Some(Key {
+                key: self.string_contents(),
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn scalar(&self) -> Option<Scalar<'buf>> {
+        Some(match self.kind() {
+            EntryKind::Null => Scalar::Null,
+            EntryKind::False => Scalar::Boolean(false),
+            EntryKind::True => Scalar::Boolean(true),
+            EntryKind::Number => Scalar::Number(Number {
+                number: self.as_bytes(),
+                start: self.entry().start,
+            }),
+            EntryKind::String => {
+                let string = self.string_contents();
+                Scalar::String(String {
+                    string,
+                    quoted: Some(self.as_bytes()),
+                    escaped: string.contains('\\'),
+                    start: self.entry().start,
+                })
+            }
+            EntryKind::Key | EntryKind::Array | EntryKind::Object => return None,
+        })
+    }
+
+    fn string_contents(&self) -> &'buf str {
+        let quoted = self.as_bytes();
+        // Never panics: the string was validated as UTF-8 by `parse_string`.
+        from_utf8(&quoted[1..quoted.len() - 1]).unwrap()
+    }
+}
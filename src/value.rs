@@ -0,0 +1,119 @@
+use alloc::{string::String as OwnedString, vec, vec::Vec};
+
+use crate::{parse, Event, Key, Result, Scalar, String, Val};
+
+// An owned document, for tools that don't need the zero-copy API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    // The number as written, so that no precision is lost.
+    Number(OwnedString),
+    // Unescaped. Lone surrogates are replaced with U+FFFD REPLACEMENT CHARACTER.
+    String(OwnedString),
+    Array(Vec<Value>),
+    // Members in input order, with unescaped keys. Duplicate keys are kept.
+    Object(Vec<(OwnedString, Value)>),
+}
+
+impl Value {
+    // Reads `val` into an owned value, without recursion. For a partly read array or
+    // object, only the remaining members are read.
+    pub fn from_val(val: Val<'_, '_>) -> Result<Value> {
+        let (parser, depth, root) = match val {
+            Val::Array(a) => (a.parser, a.depth, Value::Array(Vec::new())),
+            Val::Object(o) => (o.parser, o.depth, Value::Object(Vec::new())),
+            Val::Null(_) => return Ok(Value::Null),
+            Val::Boolean(b, _) => return Ok(Value::Bool(b)),
+            Val::Number(n) => return Ok(Value::Number(n.as_str().into())),
+            Val::String(s) => return Ok(Value::String(s.chars().collect())),
+        };
+        if parser.depth() < depth {
+            // Already closed.
+            return Ok(root);
+        }
+        // Unread children are skipped, as by `next`.
+        parser.ascend_to(depth)?;
+
+        // Open containers, with the keys they will be stored under.
+        let mut stack = vec![(None, root)];
+        let mut key = None;
+        while let Some(event) = parser.next_event()? {
+            let value = match event {
+                Event::Key(k) => {
+                    key = Some(unescaped_key(k));
+                    continue;
+                }
+                Event::StartArray => {
+                    stack.push((key.take(), Value::Array(Vec::new())));
+                    continue;
+                }
+                Event::StartObject => {
+                    stack.push((key.take(), Value::Object(Vec::new())));
+                    continue;
+                }
+                Event::EndArray | Event::EndObject => {
+                    let Some((k, value)) = stack.pop() else {
+                        break;
+                    };
+                    if stack.is_empty() {
+                        return Ok(value);
+                    }
+                    key = k;
+                    value
+                }
+                Event::Null => Value::Null,
+                Event::Bool(b) => Value::Bool(b),
+                Event::Number(n) => Value::Number(n.as_str().into()),
+                Event::String(s) => Value::String(s.chars().collect()),
+            };
+            match stack.last_mut() {
+                Some((_, Value::Array(elements))) => elements.push(value),
+                Some((_, Value::Object(members))) => {
+                    members.push((key.take().unwrap_or_default(), value))
+                }
+                _ => (),
+            }
+        }
+        // Never happens: the events end with the closing bracket of `val`.
+        Ok(Value::Null)
+    }
+
+    // The zero-copy form of a scalar. Arrays and objects have none, and neither do
+    // strings that JSON would need to escape or numbers that aren't valid JSON.
+    pub fn as_borrowed(&self) -> Option<Scalar<'_>> {
+        Some(match self {
+            Value::Null => Scalar::Null,
+            Value::Bool(b) => Scalar::Boolean(*b),
+            Value::Number(n) => {
+                // INVARIANT A: `Number` may only hold valid number text, and anything can
+                // be put in `Value::Number`.
+                let mut parser = parse(n.as_bytes());
+                let number = parser.parse_number().ok()?;
+                if parser.offset() != n.len() {
+                    return None;
+                }
+                Scalar::Number(number)
+            }
+            Value::String(s) if !s.contains(|c| c < ' ' || c == '"' || c == '\\') => {
+                Scalar::String(String {
+                    string: s,
+                    quoted: None,
+                    escaped: false,
+                    start: 0,
+                })
+            }
+            Value::String(_) | Value::Array(_) | Value::Object(_) => return None,
+        })
+    }
+}
+
+fn unescaped_key(key: Key<'_>) -> OwnedString {
+    let string = String {
+        string: key.key,
+        quoted: None,
+        escaped: true,
+        start: 0,
+    };
+    string.chars().collect()
+}
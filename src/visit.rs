@@ -0,0 +1,83 @@
+use crate::{Event, Key, Number, Parser, Result, String};
+
+// What `Parser::walk` does after a `Visitor` callback.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Flow {
+    Continue,
+    // Skips and validates the array or object just entered, without calling `exit_*`,
+    // or the value of the key just visited. The same as `Continue` for scalars.
+    Skip,
+    // Stops the walk.
+    Abort,
+}
+
+// Callbacks for `Parser::walk`. All of them continue by default.
+#[allow(unused_variables)]
+pub trait Visitor<'buf> {
+    fn visit_null(&mut self) -> Flow {
+        Flow::Continue
+    }
+
+    fn visit_bool(&mut self, value: bool) -> Flow {
+        Flow::Continue
+    }
+
+    fn visit_number(&mut self, number: Number<'buf>) -> Flow {
+        Flow::Continue
+    }
+
+    fn visit_string(&mut self, string: String<'buf>) -> Flow {
+        Flow::Continue
+    }
+
+    fn visit_key(&mut self, key: Key<'buf>) -> Flow {
+        Flow::Continue
+    }
+
+    fn enter_array(&mut self) -> Flow {
+        Flow::Continue
+    }
+
+    fn exit_array(&mut self) -> Flow {
+        Flow::Continue
+    }
+
+    fn enter_object(&mut self) -> Flow {
+        Flow::Continue
+    }
+
+    fn exit_object(&mut self) -> Flow {
+        Flow::Continue
+    }
+}
+
+impl<'buf> Parser<'buf> {
+    // Feeds the rest of the document to `visitor`, without recursion. Returns
+    // `false` if the visitor aborted. Call `finalize` to check the rest of the input.
+    pub fn walk(&mut self, visitor: &mut impl Visitor<'buf>) -> Result<bool> {
+        while let Some(event) = self.next_event()? {
+            let flow = match event {
+                Event::StartObject => visitor.enter_object(),
+                Event::Key(key) => visitor.visit_key(key),
+                Event::EndObject => visitor.exit_object(),
+                Event::StartArray => visitor.enter_array(),
+                Event::EndArray => visitor.exit_array(),
+                Event::String(string) => visitor.visit_string(string),
+                Event::Number(number) => visitor.visit_number(number),
+                Event::Bool(value) => visitor.visit_bool(value),
+                Event::Null => visitor.visit_null(),
+            };
+            match (flow, event) {
+                (Flow::Abort, _) => return Ok(false),
+                (Flow::Skip, Event::StartObject | Event::StartArray) => {
+                    self.ascend_to(self.depth() - 1)?;
+                }
+                (Flow::Skip, Event::Key(_)) => {
+                    self.skip_value()?;
+                }
+                _ => (),
+            }
+        }
+        Ok(true)
+    }
+}
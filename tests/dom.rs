@@ -0,0 +1,78 @@
+use tiny_json_parser::{key, parse, string, ErrorKind, Node, Scalar};
+
+const JSON: &[u8] =
+    br#"{"id": 7, "tags": ["a", ["b"], "c"], "owner": {"name": "x\ty", "id" : null}, "id": 8}"#;
+
+#[test]
+fn dom() {
+    let mut arena = [Node::default(); 32];
+    let dom = parse(JSON).dom(&mut arena).unwrap();
+    assert_eq!(dom.len(), 17);
+    let root = dom.root();
+    assert_eq!(root.len(), 4);
+
+    // The first member with a key wins.
+    let Some(Scalar::Number(id)) = root.get("id").unwrap().scalar() else {
+        panic!();
+    };
+    assert_eq!(id.as_u64(), Ok(7));
+
+    let tags = root.get("tags").unwrap();
+    assert_eq!(tags.len(), 3);
+    assert_eq!(tags.at(2).unwrap().node(), &Node::String(string("c")));
+    assert_eq!(
+        tags.at(1).unwrap().at(0).unwrap().scalar(),
+        Some(Scalar::String(string("b")))
+    );
+    assert!(tags.at(3).is_none());
+    assert!(tags.get("a").is_none());
+    let tags: Vec<_> = tags.elements().map(|t| t.len()).collect();
+    assert_eq!(tags, [0, 1, 0]);
+
+    let owner = root.get("owner").unwrap();
+    let Node::String(name) = owner.get("name").unwrap().node() else {
+        panic!();
+    };
+    assert!(name.has_escapes());
+    assert_eq!(owner.get("id").unwrap().scalar(), Some(Scalar::Null));
+    assert!(owner.get("missing").is_none());
+
+    let keys: Vec<_> = root.members().map(|(k, _)| k).collect();
+    assert_eq!(keys, [key("id"), key("tags"), key("owner"), key("id")]);
+    assert!(root.at(0).is_none());
+}
+
+#[test]
+fn scalars_and_empty() {
+    let mut arena = [Node::default(); 3];
+    let dom = parse(b"true").dom(&mut arena).unwrap();
+    assert_eq!(dom.root().scalar(), Some(Scalar::Boolean(true)));
+    assert_eq!(dom.root().elements().count(), 0);
+
+    let dom = parse(b"[{}, []]").dom(&mut arena).unwrap();
+    let root = dom.root();
+    assert_eq!(root.len(), 2);
+    assert!(root.at(0).unwrap().is_empty());
+    let Node::Array(empty) = root.at(1).unwrap().node() else {
+        panic!();
+    };
+    assert_eq!((empty.len(), empty.end()), (0, 3));
+}
+
+#[test]
+fn errors() {
+    let mut arena = [Node::default(); 3];
+    let err = parse(br#"{"a": 1, "b" : 2}"#).dom(&mut arena).unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::ArenaFull, 9));
+    let err = parse(b"[1, [2, 3]]").dom(&mut arena).unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::ArenaFull, 5));
+    let err = parse(b"[1, 2,]").dom(&mut arena).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ExpectedValue);
+    let err = parse(b"{} {}").dom(&mut arena).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TrailingData);
+
+    let mut p = parse(b"[]");
+    p.value().unwrap();
+    let err = p.dom(&mut []).unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::NotAtStart, 1));
+}
@@ -0,0 +1,192 @@
+use tiny_json_parser::{parse, ErrorKind, Val};
+
+fn error_kind(json: &[u8]) -> ErrorKind {
+    let mut p = parse(json);
+    let result = match p.value() {
+        Ok(Val::Array(mut a)) => (|| {
+            while a.next()?.is_some() {}
+            Ok(())
+        })(),
+        Ok(Val::Object(mut o)) => (|| {
+            while o.next()?.is_some() {}
+            Ok(())
+        })(),
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    };
+    result.and_then(|()| p.finalize()).unwrap_err().kind()
+}
+
+#[test]
+fn kinds() {
+    assert_eq!(error_kind(b""), ErrorKind::UnexpectedEof);
+    assert_eq!(error_kind(b"[1, 2"), ErrorKind::UnexpectedEof);
+    assert_eq!(error_kind(b"tru"), ErrorKind::UnexpectedEof);
+    assert_eq!(error_kind(b"nul!"), ErrorKind::UnknownLiteral);
+    assert_eq!(error_kind(b"x"), ErrorKind::ExpectedValue);
+    assert_eq!(error_kind(b"[1 2]"), ErrorKind::ExpectedCommaOrClose);
+    assert_eq!(error_kind(br#"{"a" 1}"#), ErrorKind::ExpectedColon);
+    assert_eq!(error_kind(br#"{1: 1}"#), ErrorKind::ExpectedString);
+    assert_eq!(
+        error_kind(br#"{"a": [{"b": 1]}}"#),
+        ErrorKind::MismatchedBracket
+    );
+    assert_eq!(error_kind(b"-"), ErrorKind::UnexpectedEof);
+    assert_eq!(error_kind(b"-a"), ErrorKind::InvalidNumber);
+    assert_eq!(error_kind(b"1.e5"), ErrorKind::InvalidNumber);
+    assert_eq!(error_kind(b"1e+]"), ErrorKind::InvalidNumber);
+    assert_eq!(error_kind(br#""\x""#), ErrorKind::InvalidEscape);
+    assert_eq!(error_kind(br#""\u12G4""#), ErrorKind::InvalidEscape);
+    assert_eq!(error_kind(b"\"a\nb\""), ErrorKind::ControlCharInString);
+    assert_eq!(error_kind(b"\"\xff\""), ErrorKind::InvalidUtf8);
+    assert_eq!(error_kind(b"null null"), ErrorKind::TrailingData);
+}
+
+// Skipping an unread value used to accept any closing bracket, so `[1}` passed as long
+// as nobody read the array's elements.
+#[test]
+fn mismatched_brackets() {
+    for json in [&b"[1}"[..], br#"{"a": 1]"#, b"[[1}]"] {
+        let mut p = parse(json);
+        p.value().unwrap();
+        let err = p.finalize().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MismatchedBracket);
+    }
+}
+
+#[test]
+fn display() {
+    let mut p = parse(br#"{"a" 1}"#);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    assert_eq!(o.next().unwrap_err().to_string(), "expected ':'");
+    assert_eq!(
+        ErrorKind::UnexpectedEof.to_string(),
+        "unexpected end of input"
+    );
+}
+
+#[test]
+fn offsets() {
+    let json = b"{\n  \"a\": 1,\n  \"b\" 2\n}";
+    let mut p = parse(json);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    assert!(o.next().unwrap().is_some());
+    let err = o.next().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ExpectedColon);
+    assert_eq!(err.offset(), 18);
+    let pos = p.position(err.offset());
+    assert_eq!((pos.line, pos.column), (3, 7));
+    assert_eq!(
+        format!("config.json:{}: {}", pos, err),
+        "config.json:3:7: expected ':'"
+    );
+}
+
+#[test]
+fn positions() {
+    let json = "[\"äö\", x]".as_bytes();
+    let mut p = parse(json);
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    assert!(a.next().unwrap().is_some());
+    let err = a.next().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ExpectedValue);
+    assert_eq!(err.offset(), 9);
+    let pos = p.position(err.offset());
+    assert_eq!((pos.line, pos.column), (1, 8));
+
+    let p = parse(b"\"\xff\"");
+    assert_eq!(p.position(1000).column, 4);
+    assert_eq!(parse(b"\"ab\xff\"").value().unwrap_err().offset(), 3);
+    assert_eq!(parse(b"[nul").skip_value().unwrap_err().offset(), 4);
+    assert_eq!(parse(b"[nul!").skip_value().unwrap_err().offset(), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std_adapters() {
+    use tiny_json_parser::parse_reader;
+
+    let err: Box<dyn std::error::Error> = Box::new(parse(b"").value().unwrap_err());
+    assert_eq!(err.to_string(), "unexpected end of input");
+
+    let mut buf = [0; 16];
+    let mut p = parse_reader(&b"[true]"[..], &mut buf).unwrap();
+    assert!(matches!(p.value(), Ok(Val::Array(_))));
+    assert!(p.finalize().is_ok());
+
+    let mut buf = [0; 4];
+    let err = parse_reader(&b"[true]"[..], &mut buf).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    // Fails with `Interrupted` once before each read, including the check for more input.
+    struct Interrupting<'a>(&'a [u8], bool);
+    impl std::io::Read for Interrupting<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.1 = !self.1;
+            if self.1 {
+                return Err(std::io::ErrorKind::Interrupted.into());
+            }
+            self.0.read(buf)
+        }
+    }
+    let mut buf = [0; 6];
+    let mut p = parse_reader(Interrupting(b"[true]", false), &mut buf).unwrap();
+    assert!(p.skip_value().is_ok());
+
+    let err: std::io::Error = parse(b"x").value().unwrap_err().into();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn max_depth() {
+    use tiny_json_parser::{parse_with, ParserConfig, MAX_DEPTH};
+
+    let config = ParserConfig::default().with_max_depth(2).unwrap();
+    let mut p = parse_with(b"[[1], [[2]]]", config);
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    let Ok(Some(Val::Array(mut b))) = a.next() else {
+        panic!();
+    };
+    assert!(b.next().unwrap().is_some());
+    let Ok(Some(Val::Array(mut b))) = a.next() else {
+        panic!();
+    };
+    let err = b.next().unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::TooDeep, 7));
+
+    // Skipped subtrees are limited as well.
+    let mut p = parse_with(br#"{"a": {"b": [true]}}"#, config);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    assert!(o.next().unwrap().is_some());
+    assert_eq!(o.next().unwrap_err().kind(), ErrorKind::TooDeep);
+
+    let nested = |depth| {
+        let mut json = vec![b'['; depth];
+        json.extend(vec![b']'; depth]);
+        let mut p = parse(&json);
+        p.value()?;
+        p.finalize()
+    };
+    assert!(nested(MAX_DEPTH).is_ok());
+    assert_eq!(
+        nested(MAX_DEPTH + 1).unwrap_err().kind(),
+        ErrorKind::TooDeep
+    );
+
+    assert_eq!(ParserConfig::default().max_depth(), MAX_DEPTH);
+    let err = ParserConfig::default()
+        .with_max_depth(MAX_DEPTH + 1)
+        .unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::InvalidConfig, 0));
+    assert_eq!(err.to_string(), "invalid parser configuration");
+}
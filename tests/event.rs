@@ -0,0 +1,85 @@
+use tiny_json_parser::{key, parse, string, ErrorKind, Event};
+
+fn events(json: &[u8]) -> Result<Vec<(Event<'_>, usize)>, (ErrorKind, usize)> {
+    let mut p = parse(json);
+    let mut events = Vec::new();
+    let result = (|| {
+        while let Some(event) = p.next_event()? {
+            events.push((event, p.depth()));
+        }
+        p.finalize()
+    })();
+    result.map(|()| events).map_err(|e| (e.kind(), e.offset()))
+}
+
+#[test]
+fn events_and_depths() {
+    let json = br#" {"a": [1, {"b": null}, []], "c\n": "x", "d": {}} "#;
+    let events = events(json).unwrap();
+    let kinds: Vec<_> = events
+        .iter()
+        .map(|(e, depth)| {
+            let e = match e {
+                Event::Number(n) => n.as_str(),
+                Event::Key(_) => "key",
+                Event::String(_) => "string",
+                Event::StartObject => "{",
+                Event::EndObject => "}",
+                Event::StartArray => "[",
+                Event::EndArray => "]",
+                Event::Bool(_) => "bool",
+                Event::Null => "null",
+            };
+            (e, *depth)
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ("{", 1),
+            ("key", 1),
+            ("[", 2),
+            ("1", 2),
+            ("{", 3),
+            ("key", 3),
+            ("null", 3),
+            ("}", 2),
+            ("[", 3),
+            ("]", 2),
+            ("]", 1),
+            ("key", 1),
+            ("string", 1),
+            ("key", 1),
+            ("{", 2),
+            ("}", 1),
+            ("}", 0),
+        ]
+    );
+    assert_eq!(events[1].0, Event::Key(key("a")));
+    assert_eq!(events[11].0, Event::Key(key("c\\n")));
+    assert_eq!(events[12].0, Event::String(string("x")));
+}
+
+#[test]
+fn scalars() {
+    assert_eq!(events(b" true "), Ok(vec![(Event::Bool(true), 0)]));
+    assert_eq!(events(b"null"), Ok(vec![(Event::Null, 0)]));
+    assert_eq!(
+        events(b"[]"),
+        Ok(vec![(Event::StartArray, 1), (Event::EndArray, 0)])
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(events(b""), Err((ErrorKind::UnexpectedEof, 0)));
+    assert_eq!(events(b"[1 2]"), Err((ErrorKind::ExpectedCommaOrClose, 3)));
+    assert_eq!(events(b"[1,]"), Err((ErrorKind::ExpectedValue, 3)));
+    assert_eq!(events(b"[,1]"), Err((ErrorKind::ExpectedValue, 1)));
+    assert_eq!(events(b"{\"a\" 1}"), Err((ErrorKind::ExpectedColon, 5)));
+    assert_eq!(events(b"{\"a\": 1,}"), Err((ErrorKind::ExpectedString, 8)));
+    assert_eq!(events(b"{\"a\":}"), Err((ErrorKind::ExpectedValue, 5)));
+    assert_eq!(events(b"[1}"), Err((ErrorKind::MismatchedBracket, 2)));
+    assert_eq!(events(b"[[1]"), Err((ErrorKind::UnexpectedEof, 4)));
+    assert_eq!(events(b"1 2"), Err((ErrorKind::TrailingData, 2)));
+}
@@ -0,0 +1,80 @@
+use tiny_json_parser::{json_path, parse, ErrorKind, Scalar};
+
+const JSON: &[u8] = br#"{
+    "id": 17,
+    "sensors": [
+        {"kind": "temp", "value": 21.5},
+        {"kind": "hum", "value": 40, "raw": [1, 2, 3]}
+    ],
+    "meta": {"fw": "1.2.3", "ok": true, "note": null},
+    "trailer": [1, 2]
+}"#;
+
+#[test]
+fn extract() {
+    let mut p = parse(JSON);
+    let mut slots = [None; 6];
+    p.extract(
+        [
+            &json_path!("meta", "fw"),
+            &json_path!("id"),
+            &json_path!("sensors", 1, "value"),
+            &json_path!("sensors", 1, "raw", 2),
+            &json_path!("meta", "note"),
+            &json_path!("missing"),
+        ],
+        &mut slots,
+    )
+    .unwrap();
+    let [Some(Scalar::String(fw)), Some(Scalar::Number(id)), Some(Scalar::Number(value)), Some(Scalar::Number(raw)), Some(Scalar::Null), None] =
+        slots
+    else {
+        panic!("{:?}", slots);
+    };
+    assert_eq!(fw.as_str(), "1.2.3");
+    assert_eq!(id.as_u32(), Ok(17));
+    assert_eq!(value.as_u32(), Ok(40));
+    assert_eq!(raw.as_u32(), Ok(3));
+
+    // Everything is scanned when looking for a missing path.
+    let bad = br#"{"id": 1, "meta": {}, "trailer" [1, 2]}"#;
+    let err = parse(bad)
+        .extract([&json_path!("missing"), &json_path!("id")], &mut [None; 2])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ExpectedColon);
+    parse(bad)
+        .extract(
+            [&json_path!("meta", "x"), &json_path!("id")],
+            &mut [None; 2],
+        )
+        .unwrap_err();
+}
+
+#[test]
+fn stops_early() {
+    let mut p = parse(JSON);
+    let mut slots = [None; 2];
+    p.extract([&json_path!("meta", "ok"), &json_path!("id")], &mut slots)
+        .unwrap();
+    assert_eq!(slots[0], Some(Scalar::Boolean(true)));
+    assert!(matches!(slots[1], Some(Scalar::Number(_))));
+    p.finalize().unwrap();
+
+    // The rest is only validated on request.
+    let mut p = parse(br#"{"id": 1, "trailer": [1 2]}"#);
+    p.extract([&json_path!("id")], &mut [None; 1]).unwrap();
+    let err = p.finalize().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ExpectedCommaOrClose);
+}
+
+#[test]
+fn expected_scalar() {
+    let mut slots = [None; 1];
+    let err = parse(JSON)
+        .extract([&json_path!("meta")], &mut slots)
+        .unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::ExpectedScalar, 148));
+    assert_eq!(parse(b"[1]").extract([], &mut []), Ok(()));
+    let mut slots = [None; 1];
+    parse(b"[1]").extract([&[]], &mut slots).unwrap_err();
+}
@@ -0,0 +1,69 @@
+use tiny_json_parser::{parse, ErrorKind, Scalar, Val};
+
+const JSON: &[u8] = br#"{"kind": "point", "meta": {"x": 0}, "x": 1, "yA": 2, "x": 3}"#;
+
+#[test]
+fn get() {
+    let mut p = parse(JSON);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let Ok(Some(Val::Number(x))) = o.get("x") else {
+        panic!();
+    };
+    assert_eq!(x.as_u64(), Ok(1));
+    let Ok(Some(Val::Number(y))) = o.get("yA") else {
+        panic!();
+    };
+    assert_eq!(y.as_u64(), Ok(2));
+    // Only the remaining members are scanned.
+    assert_eq!(o.get("kind"), Ok(None));
+    assert_eq!(o.next(), Ok(None));
+    p.finalize().unwrap();
+
+    let mut p = parse(JSON);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let Ok(Some(Val::Object(mut meta))) = o.get("meta") else {
+        panic!();
+    };
+    assert!(matches!(meta.get("x"), Ok(Some(Val::Number(_)))));
+    assert!(o.get("missing").unwrap().is_none());
+    p.finalize().unwrap();
+}
+
+#[test]
+fn get_many() {
+    let mut p = parse(JSON);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let [y, missing, kind, x] = o.get_many(["yA", "z", "kind", "x"]).unwrap();
+    let (Some(Scalar::Number(y)), Some(Scalar::Number(x))) = (y, x) else {
+        panic!();
+    };
+    assert_eq!((x.as_u64(), y.as_u64()), (Ok(1), Ok(2)));
+    assert_eq!(missing, None);
+    let Some(Scalar::String(kind)) = kind else {
+        panic!();
+    };
+    assert_eq!(kind.as_str(), "point");
+
+    // The object can still be read from the start, also after it was closed.
+    assert!(o.next().unwrap().is_some());
+    while o.next().unwrap().is_some() {}
+    let [kind] = o.get_many(["kind"]).unwrap();
+    assert!(kind.is_some());
+    let [x] = o.get_many(["x"]).unwrap();
+    assert!(x.is_some());
+    while o.next().unwrap().is_some() {}
+    p.finalize().unwrap();
+
+    let mut p = parse(JSON);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let err = o.get_many(["meta"]).unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::ExpectedScalar, 26));
+}
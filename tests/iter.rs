@@ -0,0 +1,128 @@
+use tiny_json_parser::{key, parse, Error, ErrorKind, Scalar, Val};
+
+#[test]
+fn numbers() {
+    let mut p = parse(b"[1, 2.5, -3]");
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    let mut sum = 0.0;
+    for n in a.numbers() {
+        sum += n.unwrap().as_f64().unwrap();
+    }
+    assert_eq!(sum, 0.5);
+    p.finalize().unwrap();
+
+    let mut p = parse(b"[1, \"2\", 3]");
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    let mut numbers = a.numbers();
+    assert!(numbers.next().unwrap().is_ok());
+    let err = numbers.next().unwrap().unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::ExpectedNumber, 4));
+    assert!(numbers.next().is_none());
+}
+
+#[test]
+fn strings() {
+    let mut p = parse(br#"["a", "b\n", "c"]"#);
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    let strings: Result<Vec<_>, _> = a.strings().map(|s| s.map(|s| s.as_str())).collect();
+    assert_eq!(strings, Ok(vec!["a", "b\\n", "c"]));
+
+    let mut p = parse(br#"["a", [1]]"#);
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    let err = a.strings().find_map(|s| s.err()).unwrap();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::ExpectedString, 6));
+}
+
+#[test]
+fn scalar_entries() {
+    let mut p = parse(br#"{"a": 1, "b": null, "c": {}}"#);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let mut entries = o.scalar_entries();
+    let (k, v) = entries.next().unwrap().unwrap();
+    assert_eq!(k, key("a"));
+    assert!(matches!(v, Scalar::Number(_)));
+    assert_eq!(entries.next(), Some(Ok((key("b"), Scalar::Null))));
+    let err = entries.next().unwrap().unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::ExpectedScalar, 25));
+    assert_eq!(entries.next(), None);
+}
+
+#[test]
+fn for_each() {
+    let mut p = parse(br#"{"xs": [[1, 2], [3]], "n": 4}"#);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let mut total = 0;
+    o.try_for_each(|k, v| -> Result<(), Error> {
+        match v {
+            Val::Array(mut xs) if k == "xs" => xs.try_for_each(|x| {
+                if let Val::Array(mut x) = x {
+                    for n in x.numbers() {
+                        total += n?.as_u64().unwrap();
+                    }
+                }
+                Ok(())
+            }),
+            Val::Number(n) => {
+                total += n.as_u64().unwrap();
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    })
+    .unwrap();
+    assert_eq!(total, 10);
+    p.finalize().unwrap();
+
+    let mut p = parse(b"[1, [2, 3], 4]");
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    let mut count = 0;
+    a.for_each(|_| count += 1).unwrap();
+    assert_eq!(count, 3);
+    p.finalize().unwrap();
+
+    #[derive(Debug, PartialEq)]
+    enum MyError {
+        Json(ErrorKind),
+        Negative,
+    }
+    impl From<Error> for MyError {
+        fn from(e: Error) -> Self {
+            MyError::Json(e.kind())
+        }
+    }
+    let sum_positive = |json: &[u8]| {
+        let mut p = parse(json);
+        let Ok(Val::Array(mut a)) = p.value() else {
+            panic!();
+        };
+        let mut sum = 0;
+        a.try_for_each(|v| match v {
+            Val::Number(n) if !n.is_negative() => {
+                sum += n.as_u64().unwrap();
+                Ok(())
+            }
+            _ => Err(MyError::Negative),
+        })
+        .map(|()| sum)
+    };
+    assert_eq!(sum_positive(b"[1, 2]"), Ok(3));
+    assert_eq!(sum_positive(b"[1, -2]"), Err(MyError::Negative));
+    assert_eq!(
+        sum_positive(b"[1, 2"),
+        Err(MyError::Json(ErrorKind::UnexpectedEof))
+    );
+}
@@ -0,0 +1,148 @@
+use tiny_json_parser::{parse, Number, NumberError, Rounding, Val};
+
+fn number(json: &str) -> Number<'_> {
+    match parse(json.as_bytes()).value() {
+        Ok(Val::Number(n)) => n,
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn integers() {
+    assert_eq!(number("0").as_u64(), Ok(0));
+    assert_eq!(number("-0").as_u64(), Ok(0));
+    assert_eq!(number("42").as_u32(), Ok(42));
+    assert_eq!(number("-42").as_i32(), Ok(-42));
+    assert_eq!(number("1.0").as_i64(), Ok(1));
+    assert_eq!(number("1e2").as_i64(), Ok(100));
+    assert_eq!(number("1.5e1").as_i64(), Ok(15));
+    assert_eq!(number("12300e-2").as_i64(), Ok(123));
+    assert_eq!(number("0.0001e4").as_u64(), Ok(1));
+    assert_eq!(number("0e999999999999999999999").as_u64(), Ok(0));
+    assert_eq!(number("18446744073709551615").as_u64(), Ok(u64::MAX));
+    assert_eq!(number("-9223372036854775808").as_i64(), Ok(i64::MIN));
+    assert_eq!(
+        number("-170141183460469231731687303715884105728").as_i128(),
+        Ok(i128::MIN)
+    );
+}
+
+#[test]
+fn integer_errors() {
+    assert_eq!(number("1.5").as_i64(), Err(NumberError::NotAnInteger));
+    assert_eq!(number("1e-1").as_i64(), Err(NumberError::NotAnInteger));
+    assert_eq!(
+        number("1e-99999999999999999999").as_i64(),
+        Err(NumberError::NotAnInteger)
+    );
+    assert_eq!(number("-1").as_u64(), Err(NumberError::Overflow));
+    assert_eq!(number("4294967296").as_u32(), Err(NumberError::Overflow));
+    assert_eq!(number("2147483648").as_i32(), Err(NumberError::Overflow));
+    assert_eq!(
+        number("18446744073709551616").as_u64(),
+        Err(NumberError::Overflow)
+    );
+    assert_eq!(
+        number("-9223372036854775809").as_i64(),
+        Err(NumberError::Overflow)
+    );
+    assert_eq!(number("1e400").as_i128(), Err(NumberError::Overflow));
+}
+
+#[test]
+fn floats() {
+    assert_eq!(number("1.5").as_f64(), Ok(1.5));
+    assert_eq!(number("-2.5E-1").as_f32(), Ok(-0.25));
+    assert_eq!(number("-0").as_f64(), Ok(-0.0));
+    assert_eq!(number("0.1").as_f64(), Ok(0.1));
+    assert_eq!(number("1e300").as_f64(), Ok(1e300));
+    assert_eq!(number("9007199254740992").as_f64(), Ok(9007199254740992.0));
+    assert_eq!(
+        number("9007199254740993").as_f64(),
+        Err(NumberError::PrecisionLost)
+    );
+    assert_eq!(number("16777217").as_f32(), Err(NumberError::PrecisionLost));
+    assert_eq!(number("1e400").as_f64(), Err(NumberError::Overflow));
+    assert_eq!(number("1e39").as_f32(), Err(NumberError::Overflow));
+    assert_eq!(number("1e-400").as_f64(), Err(NumberError::PrecisionLost));
+    assert_eq!(number("0e-400").as_f64(), Ok(0.0));
+}
+
+#[test]
+fn decomposition() {
+    let n = number("-12.050e-3");
+    assert!(n.is_negative() && n.has_fraction() && n.has_exponent());
+    assert!(!n.is_integer() && !n.is_negative_zero());
+    assert_eq!(n.sign(), -1);
+    assert_eq!(n.integer_digits(), b"12");
+    assert_eq!(n.fraction_digits(), b"050");
+    assert_eq!(n.exponent(), Ok(-3));
+
+    let n = number("7");
+    assert!(n.is_integer() && !n.is_negative());
+    assert_eq!(n.sign(), 1);
+    assert_eq!(n.integer_digits(), b"7");
+    assert_eq!(n.fraction_digits(), b"");
+    assert_eq!(n.exponent(), Ok(0));
+
+    assert_eq!(number("1E+10").exponent(), Ok(10));
+    assert!(number("1E+10").has_exponent() && !number("1E+10").has_fraction());
+    assert_eq!(
+        number("1e3000000000").exponent(),
+        Err(NumberError::Overflow)
+    );
+
+    assert!(number("-0").is_negative_zero());
+    assert!(number("-0.00e7").is_negative_zero());
+    assert!(!number("0").is_negative_zero());
+    assert!(!number("-0.1").is_negative_zero());
+}
+
+#[test]
+fn fixed_point() {
+    assert_eq!(number("12.34").to_fixed::<2>(), Ok(1234));
+    assert_eq!(number("12.34").to_fixed::<4>(), Ok(123400));
+    assert_eq!(number("-0.05").to_fixed::<2>(), Ok(-5));
+    assert_eq!(number("1.2300").to_fixed::<2>(), Ok(123));
+    assert_eq!(number("1.5e-2").to_fixed::<3>(), Ok(15));
+    assert_eq!(number("15e3").to_fixed::<0>(), Ok(15000));
+    assert_eq!(number("0.0").to_fixed::<0>(), Ok(0));
+    assert_eq!(
+        number("12.345").to_fixed::<2>(),
+        Err(NumberError::PrecisionLost)
+    );
+    assert_eq!(
+        number("1e-400").to_fixed::<18>(),
+        Err(NumberError::PrecisionLost)
+    );
+    assert_eq!(
+        number("92233720368547758.08").to_fixed::<2>(),
+        Err(NumberError::Overflow)
+    );
+    assert_eq!(
+        number("-92233720368547758.08").to_fixed::<2>(),
+        Ok(i64::MIN)
+    );
+}
+
+#[test]
+fn rounding() {
+    let fixed = |json, rounding| number(json).to_fixed_with(2, rounding);
+    assert_eq!(fixed("1.235", Rounding::TowardZero), Ok(123));
+    assert_eq!(fixed("-1.239", Rounding::TowardZero), Ok(-123));
+    assert_eq!(fixed("1.235", Rounding::HalfAwayFromZero), Ok(124));
+    assert_eq!(fixed("-1.235", Rounding::HalfAwayFromZero), Ok(-124));
+    assert_eq!(fixed("1.2349", Rounding::HalfAwayFromZero), Ok(123));
+    assert_eq!(fixed("1.225", Rounding::HalfEven), Ok(122));
+    assert_eq!(fixed("1.235", Rounding::HalfEven), Ok(124));
+    assert_eq!(fixed("1.2250001", Rounding::HalfEven), Ok(123));
+    assert_eq!(fixed("0.004", Rounding::HalfEven), Ok(0));
+    assert_eq!(fixed("0.006", Rounding::HalfEven), Ok(1));
+    assert_eq!(fixed("5e-3", Rounding::HalfAwayFromZero), Ok(1));
+    assert_eq!(fixed("5e-4", Rounding::HalfAwayFromZero), Ok(0));
+    assert_eq!(fixed("1.2300", Rounding::Exact), Ok(123));
+    assert_eq!(
+        fixed("1.231", Rounding::Exact),
+        Err(NumberError::PrecisionLost)
+    );
+}
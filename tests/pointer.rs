@@ -0,0 +1,127 @@
+use tiny_json_parser::{key, parse, string, ErrorKind, Val};
+
+const JSON: &[u8] = br#"{
+    "devices": [
+        {"name": "a", "on": true},
+        {"name": "b", "tags": [1, [2, 3]]},
+        {"name": "c"},
+        {"name": "d", "id": 4}
+    ],
+    "a/b": 1,
+    "m~n": 2,
+    "": 3,
+    "\u00e9": 4,
+    "x": {"": {"": 5}}
+}"#;
+
+fn number(pointer: &str) -> Option<String> {
+    match parse(JSON).pointer(pointer).unwrap() {
+        Some(Val::Number(n)) => Some(n.as_str().to_owned()),
+        None => None,
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn lookup() {
+    let mut p = parse(JSON);
+    assert_eq!(
+        p.pointer("/devices/3/name"),
+        Ok(Some(Val::String(string("d"))))
+    );
+    p.finalize().unwrap();
+
+    assert_eq!(number("/devices/3/id").as_deref(), Some("4"));
+    assert_eq!(number("/devices/1/tags/1/0").as_deref(), Some("2"));
+    assert_eq!(number("/a~1b").as_deref(), Some("1"));
+    assert_eq!(number("/m~0n").as_deref(), Some("2"));
+    assert_eq!(number("/").as_deref(), Some("3"));
+    assert_eq!(number("/é").as_deref(), Some("4"));
+    assert_eq!(number("/x//").as_deref(), Some("5"));
+
+    let mut p = parse(JSON);
+    let Ok(Some(Val::Object(mut o))) = p.pointer("/devices/0") else {
+        panic!();
+    };
+    assert_eq!(o.next(), Ok(Some((key("name"), Val::String(string("a"))))));
+    assert!(matches!(parse(JSON).pointer(""), Ok(Some(Val::Object(_)))));
+}
+
+#[test]
+fn not_found() {
+    for pointer in [
+        "/nope",
+        "/devices/4",
+        "/devices/01",
+        "/devices/-",
+        "/devices/x",
+        "/devices/0/name/x",
+        "/a~1b/0",
+        "/x/y",
+    ] {
+        assert_eq!(parse(JSON).pointer(pointer), Ok(None), "{}", pointer);
+    }
+}
+
+#[test]
+fn errors() {
+    // Offsets point to the value the pointer was applied to.
+    for pointer in ["devices", "/a~2b", "/a~", "/x/~"] {
+        let mut p = parse(b"  [1]");
+        let err = p.pointer(pointer).unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (ErrorKind::InvalidPointer, 2));
+        // A rejected pointer leaves the parser where it was.
+        assert_eq!(p.offset(), 0);
+    }
+
+    let err = parse(br#"{"a": [1 2], "b": 1}"#)
+        .pointer("/a/1")
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ExpectedCommaOrClose);
+}
+
+#[test]
+fn seg_paths() {
+    use tiny_json_parser::{json_path, Seg};
+
+    let mut p = parse(JSON);
+    assert_eq!(
+        p.query(json_path!("devices", 3, "name")),
+        Ok(Some(Val::String(string("d"))))
+    );
+    p.finalize().unwrap();
+
+    let mut p = parse(JSON);
+    let Ok(Some(Val::Number(n))) = p.query([Seg::Key(b"x"), Seg::Key(b""), Seg::Key(b"")]) else {
+        panic!();
+    };
+    assert_eq!(n.as_str(), "5");
+
+    let mut p = parse(JSON);
+    let Ok(Some(Val::Number(n))) = p.query(json_path!("é")) else {
+        panic!();
+    };
+    assert_eq!(n.as_str(), "4");
+    assert!(matches!(parse(JSON).query([]), Ok(Some(Val::Object(_)))));
+    assert_eq!(
+        parse(JSON).query(json_path!("a/b")).map(|v| v.is_some()),
+        Ok(true)
+    );
+
+    // Keys don't match arrays, and indices don't match objects.
+    assert_eq!(parse(JSON).query(json_path!("devices", "0")), Ok(None));
+    assert_eq!(parse(JSON).query(json_path!(0)), Ok(None));
+    assert_eq!(parse(JSON).query(json_path!("devices", 4)), Ok(None));
+
+    // Stops before the malformed part of the document.
+    let mut p = parse(br#"{"a": 1, "b": [1 2]}"#);
+    assert!(matches!(p.query(json_path!("a")), Ok(Some(Val::Number(_)))));
+    let err = parse(br#"{"b" [1], "a": 1}"#)
+        .query(json_path!("a"))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ExpectedColon);
+    let err = parse(br#"{"b": [1 2], "a": 1}"#)
+        .query(json_path!("a"))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ExpectedCommaOrClose);
+}
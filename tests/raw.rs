@@ -0,0 +1,78 @@
+use tiny_json_parser::{key, literal, parse, ErrorKind, Val};
+
+const JSON: &str = r#"{"id": 7, "payload": {"to": ["a", "b"], "msg": "hé\n" }, "s": "x\"y", "n": -1.50e+2, "ok": true, "no": null}"#;
+
+#[test]
+fn into_raw() {
+    let mut p = parse(JSON.as_bytes());
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let mut raw = |name| {
+        let Ok(Some((k, v))) = o.next() else {
+            panic!();
+        };
+        assert_eq!(k, key(name));
+        v.into_raw().unwrap()
+    };
+    assert_eq!(raw("id").as_str(), "7");
+    assert_eq!(
+        raw("payload").as_str(),
+        r#"{"to": ["a", "b"], "msg": "hé\n" }"#
+    );
+    assert_eq!(raw("s").as_bytes(), br#""x\"y""#);
+    assert_eq!(raw("n").as_str(), "-1.50e+2");
+    // Literals are slices of the input too, not static strings.
+    let ok = raw("ok").as_bytes();
+    assert_eq!(ok, b"true");
+    assert_eq!(ok.as_ptr(), JSON[JSON.find("true").unwrap()..].as_ptr());
+    let no = raw("no").as_bytes();
+    assert_eq!(no.as_ptr(), JSON[JSON.find("null").unwrap()..].as_ptr());
+    assert_eq!(o.next(), Ok(None));
+    p.finalize().unwrap();
+
+    // A partly read container from inside an array still covers its whole source text.
+    let json = b"[[null, false], []]";
+    let mut p = parse(json);
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    let Ok(Some(Val::Array(mut inner))) = a.next() else {
+        panic!();
+    };
+    assert_eq!(inner.next(), Ok(Some(Val::Null(literal("null")))));
+    let raw = Val::Array(inner).into_raw().unwrap();
+    assert_eq!(raw.as_str(), "[null, false]");
+    assert_eq!(raw.as_bytes().as_ptr(), json[1..].as_ptr());
+    assert_eq!(
+        a.next().unwrap().unwrap().into_raw().unwrap().as_str(),
+        "[]"
+    );
+}
+
+#[test]
+fn spanned_into_raw() {
+    let mut p = parse(b"[1, {\"a\": 2}]");
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    a.next().unwrap();
+    let raw = a.next_spanned().unwrap().unwrap().into_raw().unwrap();
+    assert_eq!(raw.as_str(), "{\"a\": 2}");
+}
+
+#[test]
+fn validates_raw() {
+    let mut p = parse(br#"{"a": [1, 2 3]}"#);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let Ok(Some((_, v))) = o.next() else {
+        panic!();
+    };
+    let err = v.into_raw().unwrap_err();
+    assert_eq!(
+        (err.kind(), err.offset()),
+        (ErrorKind::ExpectedCommaOrClose, 12)
+    );
+}
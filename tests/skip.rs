@@ -0,0 +1,114 @@
+use tiny_json_parser::{key, parse, ErrorKind, Val};
+
+const JSON: &[u8] = br#"{"a": [1, {"b": [true, null]}, "x"], "c": {"d": -1.5e3}, "e": 3}"#;
+
+#[test]
+fn skip_value() {
+    let mut p = parse(JSON);
+    assert_eq!(p.skip_value(), Ok(0..JSON.len()));
+    p.finalize().unwrap();
+
+    let mut p = parse(b"  [1, [2]]  ");
+    assert_eq!(p.skip_value(), Ok(2..10));
+    p.finalize().unwrap();
+
+    let mut p = parse(b" \"str\" ");
+    assert_eq!(p.skip_value(), Ok(1..6));
+}
+
+#[test]
+fn skip_rest() {
+    let mut p = parse(JSON);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let Ok(Some((_, Val::Array(mut a)))) = o.next() else {
+        panic!();
+    };
+    assert!(a.next().unwrap().is_some());
+    assert_eq!(a.skip_rest(), Ok(6..35));
+    let Ok(Some((k, v))) = o.next() else {
+        panic!();
+    };
+    assert_eq!(k, key("c"));
+    assert_eq!(v.skip(), Ok(42..55));
+    let Ok(Some((k, _, v))) = o.next_spanned() else {
+        panic!();
+    };
+    assert_eq!(k, key("e"));
+    assert_eq!(v.skip(), Ok(62..63));
+    assert_eq!(o.skip_rest(), Ok(0..JSON.len()));
+    p.finalize().unwrap();
+
+    let mut p = parse(b"[]");
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    assert_eq!(a.next(), Ok(None));
+    assert_eq!(a.skip_rest(), Ok(0..2));
+}
+
+#[test]
+fn validates_skipped() {
+    let skip = |json: &[u8]| {
+        let mut p = parse(json);
+        let Ok(Val::Array(mut a)) = p.value() else {
+            panic!();
+        };
+        a.next().unwrap();
+        a.skip_rest().map_err(|e| (e.kind(), e.offset()))
+    };
+    assert_eq!(
+        skip(b"[1, [2 3]]"),
+        Err((ErrorKind::ExpectedCommaOrClose, 7))
+    );
+    assert_eq!(skip(b"[1, [2,]]"), Err((ErrorKind::ExpectedValue, 7)));
+    assert_eq!(skip(b"[1, {\"a\" 1}]"), Err((ErrorKind::ExpectedColon, 9)));
+    assert_eq!(skip(b"[1, {1: 1}]"), Err((ErrorKind::ExpectedString, 5)));
+    assert_eq!(
+        skip(b"[1, {\"a\": 1]]"),
+        Err((ErrorKind::MismatchedBracket, 11))
+    );
+    assert_eq!(skip(b"[1, [}]"), Err((ErrorKind::MismatchedBracket, 5)));
+    assert_eq!(skip(b"[1, [nul]]"), Err((ErrorKind::UnknownLiteral, 5)));
+    assert_eq!(skip(b"[1, [\"\\x\"]]"), Err((ErrorKind::InvalidEscape, 7)));
+    assert_eq!(
+        skip(b"[1, [01]]"),
+        Err((ErrorKind::ExpectedCommaOrClose, 6))
+    );
+    assert_eq!(skip(b"[1, [[]"), Err((ErrorKind::UnexpectedEof, 7)));
+    assert_eq!(skip(b"[1 2]"), Err((ErrorKind::ExpectedCommaOrClose, 3)));
+    assert_eq!(skip(b"[1, {}, [], {\"a\": [{}]}]"), Ok(0..24));
+}
+
+#[test]
+fn spanned_skip() {
+    let mut p = parse(JSON);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+    let mut spans = Vec::new();
+    while let Some((_, _, v)) = o.next_spanned().unwrap() {
+        spans.push(v.skip().unwrap());
+    }
+    assert_eq!(spans, [6..35, 42..55, 62..63]);
+    p.finalize().unwrap();
+
+    let mut p = parse(b" null ");
+    assert_eq!(p.value_spanned().unwrap().skip(), Ok(1..5));
+}
+
+#[test]
+fn val_skip() {
+    let json = br#"[1, "a\"", true, null, false, {"x": []}, -2.5]"#;
+    let mut p = parse(json);
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    let mut spans = Vec::new();
+    while let Some(v) = a.next().unwrap() {
+        spans.push(v.skip().unwrap());
+    }
+    assert_eq!(spans, [1..2, 4..9, 11..15, 17..21, 23..28, 30..39, 41..45]);
+    p.finalize().unwrap();
+}
@@ -0,0 +1,68 @@
+use tiny_json_parser::{key, parse, Val};
+
+const JSON: &[u8] = br#"{"a": [1, {"b": null}], "c\"": "x\ny", "d": false}"#;
+
+#[test]
+fn spans() {
+    let mut p = parse(JSON);
+    let mut root = p.value_spanned().unwrap();
+    assert_eq!((root.start(), root.span()), (0, None));
+    let Val::Object(o) = &mut root.val else {
+        panic!();
+    };
+
+    let Ok(Some((k, key_span, mut a))) = o.next_spanned() else {
+        panic!();
+    };
+    assert_eq!((k, key_span), (key("a"), 1..4));
+    assert_eq!((a.start(), a.span()), (6, None));
+    let Val::Array(arr) = &mut a.val else {
+        panic!();
+    };
+    let one = arr.next_spanned().unwrap().unwrap();
+    assert_eq!(one.span(), Some(7..8));
+    let Ok(Some(mut inner)) = arr.next_spanned() else {
+        panic!();
+    };
+    let Val::Object(obj) = &mut inner.val else {
+        panic!();
+    };
+    let Ok(Some((_, key_span, null))) = obj.next_spanned() else {
+        panic!();
+    };
+    assert_eq!((key_span, null.span()), (11..14, Some(16..20)));
+    assert_eq!(obj.next(), Ok(None));
+    assert_eq!(inner.span(), Some(10..21));
+    assert_eq!(arr.next(), Ok(None));
+    assert_eq!(a.span(), Some(6..22));
+
+    let Ok(Some((_, key_span, s))) = o.next_spanned() else {
+        panic!();
+    };
+    assert_eq!(&JSON[key_span], br#""c\"""#);
+    assert_eq!(&JSON[s.span().unwrap()], br#""x\ny""#);
+    let Ok(Some((_, _, b))) = o.next_spanned() else {
+        panic!();
+    };
+    assert_eq!(&JSON[b.span().unwrap()], b"false");
+    assert!(o.next_spanned().unwrap().is_none());
+    assert_eq!(root.span(), Some(0..JSON.len()));
+    p.finalize().unwrap();
+}
+
+#[test]
+fn skipped_containers() {
+    // Unread children are skipped by `next`, closing them without exposing a span.
+    let mut p = parse(b" [[1, 2], -0.5e1 ] ");
+    let mut root = p.value_spanned().unwrap();
+    assert_eq!(root.start(), 1);
+    let Val::Array(a) = &mut root.val else {
+        panic!();
+    };
+    let inner = a.next_spanned().unwrap().unwrap();
+    assert_eq!((inner.start(), inner.span()), (2, None));
+    let n = a.next_spanned().unwrap().unwrap();
+    assert_eq!(n.span(), Some(10..16));
+    assert!(a.next_spanned().unwrap().is_none());
+    assert_eq!(root.span(), Some(1..18));
+}
@@ -0,0 +1,136 @@
+use tiny_json_parser::{parse, ErrorKind, String, Val};
+
+fn string(json: &str) -> String<'_> {
+    match parse(json.as_bytes()).value() {
+        Ok(Val::String(s)) => s,
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn unescape_into() {
+    let mut buf = [0; 32];
+    let s = string(r#""plain""#);
+    assert_eq!(s.unescaped_len(), 5);
+    assert_eq!(s.unescape_into(&mut buf), Ok("plain"));
+
+    let s = string(r#""a\"b\\c\/d\b\f\n\r\t""#);
+    assert_eq!(s.unescaped_len(), 12);
+    assert_eq!(s.unescape_into(&mut buf), Ok("a\"b\\c/d\u{8}\u{c}\n\r\t"));
+
+    let s = string(r#""étÉ €""#);
+    assert_eq!(s.unescaped_len(), "étÉ €".len());
+    assert_eq!(s.unescape_into(&mut buf), Ok("étÉ €"));
+
+    let s = string(r#""𝄞 clef""#);
+    assert_eq!(s.unescaped_len(), "𝄞 clef".len());
+    assert_eq!(s.unescape_into(&mut buf), Ok("𝄞 clef"));
+
+    let s = string(r#""""#);
+    assert_eq!(s.unescape_into(&mut []), Ok(""));
+}
+
+#[test]
+fn unescape_buffer() {
+    let s = string(r#""ab\ncd""#);
+    let mut buf = [0; 32];
+    // The error's offset is the length needed.
+    for len in [0, 2, 4] {
+        let err = s.unescape_into(&mut buf[..len]).unwrap_err();
+        assert_eq!((err.kind(), err.offset()), (ErrorKind::BufferTooSmall, 5));
+    }
+    assert_eq!(s.unescape_into(&mut buf[..5]), Ok("ab\ncd"));
+
+    // Lone surrogates are replaced, as by `unescaped_len` and `chars`.
+    for json in [r#""x\uD800""#, r#""x\uDC00\uD800""#, r#""x\uD800\n""#] {
+        let s = string(json);
+        let decoded = s.unescape_into(&mut buf).unwrap();
+        assert_eq!(decoded.len(), s.unescaped_len());
+        assert!(decoded.chars().eq(s.chars()));
+    }
+    let s = string(r#""\uD800""#);
+    assert_eq!(s.unescaped_len(), 3);
+    let err = s.unescape_into(&mut [0; 2]).unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::BufferTooSmall, 3));
+    assert_eq!(s.unescape_into(&mut [0; 3]), Ok("\u{fffd}"));
+}
+
+#[test]
+fn lazy_decoding() {
+    let s = string(r#""aé\n𝄞𝄞\"""#);
+    assert!(s.chars().eq("aé\n𝄞𝄞\"".chars()));
+    assert!(s.decoded_bytes().eq("aé\n𝄞𝄞\"".bytes()));
+    assert_eq!(s.decoded_bytes().count(), s.unescaped_len());
+
+    let s = string(r#""\uDC00x\uD800""#);
+    assert!(s.chars().eq("\u{FFFD}x\u{FFFD}".chars()));
+    assert!(s.decoded_bytes().eq("\u{FFFD}x\u{FFFD}".bytes()));
+
+    assert_eq!(string(r#""""#).chars().next(), None);
+    assert_eq!(string(r#""""#).decoded_bytes().next(), None);
+}
+
+#[test]
+fn escape_flag() {
+    let s = string(r#""no escapes here""#);
+    assert!(!s.has_escapes());
+    assert_eq!(s.as_unescaped_str(), Some("no escapes here"));
+
+    let s = string(r#""tab\there""#);
+    assert!(s.has_escapes());
+    assert_eq!(s.as_unescaped_str(), None);
+    assert_eq!(s.unescaped_len(), 8);
+}
+
+#[test]
+fn key_matches() {
+    let mut p = parse(br#"{"a\u00e9": 1, "plain": 2, "\ud834\udd1e\n": 3, "\udd1e": 4}"#);
+    let Ok(Val::Object(mut o)) = p.value() else {
+        panic!();
+    };
+
+    let (k, _) = o.next().unwrap().unwrap();
+    assert!(k.matches("aé"));
+    assert!(k == "aé" && k == *"aé");
+    assert!(!k.matches("a"));
+    assert!(!k.matches("aéé"));
+    assert!(!k.matches("a\\u00e9"));
+
+    let (k, _) = o.next().unwrap().unwrap();
+    assert!(k == "plain");
+    assert!(k != "plai" && k != "plains");
+
+    let (k, _) = o.next().unwrap().unwrap();
+    assert!(k == "𝄞\n");
+
+    let (k, _) = o.next().unwrap().unwrap();
+    assert!(k != "\u{FFFD}");
+}
+
+#[test]
+fn strict_surrogates() {
+    use tiny_json_parser::{parse_with, ParserConfig};
+
+    let strict = ParserConfig::default().with_strict_surrogates(true);
+    let lone = |json: &str| {
+        let err = parse_with(json.as_bytes(), strict).value().unwrap_err();
+        (err.kind(), err.offset())
+    };
+    assert_eq!(lone(r#""ab\uD800""#), (ErrorKind::LoneSurrogate, 3));
+    assert_eq!(lone(r#""ab\uD800x""#), (ErrorKind::LoneSurrogate, 3));
+    assert_eq!(lone(r#""ab\uD800\n""#), (ErrorKind::LoneSurrogate, 3));
+    assert_eq!(lone(r#""ab\uD800\uD800""#), (ErrorKind::LoneSurrogate, 3));
+    assert_eq!(lone(r#""ab\uDC00\uD800""#), (ErrorKind::LoneSurrogate, 3));
+    assert_eq!(
+        lone(r#""\uD834\uDD1E\uDD1E""#),
+        (ErrorKind::LoneSurrogate, 13)
+    );
+
+    let Ok(Val::String(s)) = parse_with(br#""\uD834\uDD1E\u00e9""#, strict).value() else {
+        panic!();
+    };
+    let mut buf = [0; 8];
+    assert_eq!(s.unescape_into(&mut buf), Ok("𝄞é"));
+
+    assert!(parse(br#""\uD800""#).value().is_ok());
+}
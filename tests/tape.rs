@@ -0,0 +1,93 @@
+use tiny_json_parser::{json_path, key, parse, EntryKind, ErrorKind, Scalar, TapeEntry};
+
+const JSON: &[u8] = br#"{"name": "dev", "tags" : ["a", "b\n", "c"], "nested": {"x": [1, {"y": null}]}, "ok": true}"#;
+
+#[test]
+fn tape() {
+    let mut tape = [TapeEntry::default(); 32];
+    let doc = parse(JSON).tape(&mut tape).unwrap();
+    // Keys have entries of their own.
+    assert_eq!(doc.len(), 18);
+    let root = doc.root();
+    assert_eq!(
+        (root.kind(), root.len(), root.span()),
+        (EntryKind::Object, 4, 0..JSON.len())
+    );
+    assert!(root.parent().is_none() && root.next_sibling().is_none());
+
+    let tags = root.get("tags").unwrap();
+    assert_eq!((tags.kind(), tags.len()), (EntryKind::Array, 3));
+    assert_eq!(tags.as_bytes(), br#"["a", "b\n", "c"]"#);
+    let Some(Scalar::String(b)) = tags.at(1).unwrap().scalar() else {
+        panic!();
+    };
+    assert!(b.has_escapes());
+    assert_eq!(b.as_str(), "b\\n");
+    assert!(tags.at(3).is_none());
+    assert_eq!(tags.parent().unwrap().index(), 0);
+
+    let key_tags = doc.entry(tags.index() - 1).unwrap();
+    assert_eq!(key_tags.key(), Some(key("tags")));
+    assert_eq!(key_tags.as_bytes(), br#""tags""#);
+    assert_eq!(key_tags.next_sibling().unwrap().index(), tags.index());
+
+    // Sibling skipping jumps over the whole subtree.
+    let key_nested = tags.next_sibling().unwrap();
+    assert_eq!(key_nested.key(), Some(key("nested")));
+    let nested = key_nested.next_sibling().unwrap();
+    let key_ok = nested.next_sibling().unwrap();
+    assert_eq!(key_ok.key(), Some(key("ok")));
+    assert_eq!(
+        key_ok.next_sibling().unwrap().scalar(),
+        Some(Scalar::Boolean(true))
+    );
+    assert!(key_ok.next_sibling().unwrap().next_sibling().is_none());
+
+    let y = root.query(&json_path!("nested", "x", 1, "y")).unwrap();
+    assert_eq!((y.scalar(), y.span()), (Some(Scalar::Null), 70..74));
+    assert_eq!(y.parent().unwrap().len(), 1);
+    assert!(root.query(&json_path!("nested", "x", 2)).is_none());
+    assert!(root.query(&json_path!("tags", "a")).is_none());
+    let Some(Scalar::Number(one)) = root.query(&json_path!("nested", "x", 0)).unwrap().scalar()
+    else {
+        panic!();
+    };
+    assert_eq!(one.as_u64(), Ok(1));
+}
+
+#[test]
+fn scalars_and_empty() {
+    let mut tape = [TapeEntry::default(); 4];
+    let doc = parse(b" -1.5 ").tape(&mut tape).unwrap();
+    assert_eq!((doc.len(), doc.root().span()), (1, 1..5));
+
+    let doc = parse(b"[[], {}]").tape(&mut tape).unwrap();
+    let root = doc.root();
+    assert_eq!(root.len(), 2);
+    let empty = root.at(0).unwrap();
+    assert!(empty.is_empty() && empty.first_child().is_none());
+    assert_eq!(empty.next_sibling().unwrap().kind(), EntryKind::Object);
+}
+
+#[test]
+fn errors() {
+    let mut tape = [TapeEntry::default(); 4];
+    let err = parse(b"[1, 2, [3]]").tape(&mut tape).unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::TapeFull, 8));
+    let err = parse(b"[1, 2").tape(&mut tape).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    let err = parse(b"1 2").tape(&mut tape).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TrailingData);
+    let err = parse(b"").tape(&mut []).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    // Only a whole document can be indexed.
+    let mut p = parse(b"[]");
+    p.value().unwrap();
+    let err = p.tape(&mut []).unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::NotAtStart, 1));
+    let mut p = parse(b"[1, [2]]");
+    p.next_event().unwrap();
+    let err = p.tape(&mut tape).unwrap_err();
+    assert_eq!((err.kind(), err.offset()), (ErrorKind::NotAtStart, 1));
+}
@@ -1,15 +1,17 @@
+#![allow(clippy::redundant_pattern_matching)]
+
 use std::{
     error::Error,
     fs::read_dir,
     path::{Path, PathBuf},
 };
-use tiny_json_parser::{parse, Error as JsonError, Val};
+use tiny_json_parser::{parse_with, Error as JsonError, ParserConfig, Val};
 
-fn validate(json: &[u8]) -> Result<(), JsonError> {
-    let mut p = parse(json);
+fn validate(json: &[u8], config: ParserConfig) -> Result<(), JsonError> {
+    let mut p = parse_with(json, config);
     match p.value()? {
-        Val::Null => (),
-        Val::Boolean(_) => (),
+        Val::Null(_) => (),
+        Val::Boolean(..) => (),
         Val::String(_) => (),
         Val::Number(_) => (),
         Val::Array(mut a) => while let Some(_) = a.next()? {},
@@ -17,14 +19,21 @@ fn validate(json: &[u8]) -> Result<(), JsonError> {
     }
     p.finalize()?;
 
-    let mut p = parse(json);
+    let mut p = parse_with(json, config);
     p.value()?;
     p.finalize()?;
 
+    let mut p = parse_with(json, config);
+    while p.next_event()?.is_some() {}
+    p.finalize()?;
+
     Ok(())
 }
 
-fn validate_dir(path: impl AsRef<Path>) -> Result<(), Box<dyn Error + 'static>> {
+fn validate_dir(
+    path: impl AsRef<Path>,
+    config: ParserConfig,
+) -> Result<(), Box<dyn Error + 'static>> {
     let mut dir = Vec::new();
     for entry in read_dir(path.as_ref())? {
         dir.push(entry?.file_name());
@@ -36,9 +45,13 @@ fn validate_dir(path: impl AsRef<Path>) -> Result<(), Box<dyn Error + 'static>>
         pathbuf.push(&fname);
         let fname = fname.to_string_lossy();
         if fname.ends_with(".json") {
-            let expect = &fname[0..1];
+            let mut expect = &fname[0..1];
+            // Strict mode decides these otherwise indeterminate cases.
+            if config.strict_surrogates() && expect == "i" && fname.contains("surrogate") {
+                expect = "n";
+            }
             let json = std::fs::read(&pathbuf)?;
-            match (expect, validate(&json)) {
+            match (expect, validate(&json, config)) {
                 ("y", Ok(())) => println!("OK\t{}", fname),
                 ("y", Err(_)) => {
                     errors += 1;
@@ -66,12 +79,19 @@ fn validate_dir(path: impl AsRef<Path>) -> Result<(), Box<dyn Error + 'static>>
 
 #[test]
 fn minefield() -> Result<(), Box<dyn Error + 'static>> {
-    validate_dir("tests/minefield")?;
+    validate_dir("tests/minefield", ParserConfig::default())?;
+    Ok(())
+}
+
+#[test]
+fn minefield_strict() -> Result<(), Box<dyn Error + 'static>> {
+    let config = ParserConfig::default().with_strict_surrogates(true);
+    validate_dir("tests/minefield", config)?;
     Ok(())
 }
 
 #[test]
 fn kontio() -> Result<(), Box<dyn Error + 'static>> {
-    validate_dir("tests/kontio")?;
+    validate_dir("tests/kontio", ParserConfig::default())?;
     Ok(())
 }
@@ -0,0 +1,100 @@
+#![cfg(feature = "alloc")]
+
+use tiny_json_parser::{parse, Scalar, Val, Value};
+
+fn value(json: &[u8]) -> Value {
+    let mut p = parse(json);
+    let value = Value::from_val(p.value().unwrap()).unwrap();
+    p.finalize().unwrap();
+    value
+}
+
+#[test]
+fn from_val() {
+    let json = r#" {"b": [1, -2.50e1, {"cé": null}], "a\n": "x\"𝄞", "e": [], "b": true} "#;
+    assert_eq!(
+        value(json.as_bytes()),
+        Value::Object(vec![
+            (
+                "b".into(),
+                Value::Array(vec![
+                    Value::Number("1".into()),
+                    Value::Number("-2.50e1".into()),
+                    Value::Object(vec![("c\u{e9}".into(), Value::Null)]),
+                ])
+            ),
+            ("a\n".into(), Value::String("x\"\u{1d11e}".into())),
+            ("e".into(), Value::Array(vec![])),
+            ("b".into(), Value::Bool(true)),
+        ])
+    );
+    assert_eq!(value(b"false"), Value::Bool(false));
+    assert_eq!(value(br#""\uD800""#), Value::String("\u{fffd}".into()));
+}
+
+#[test]
+fn partly_read() {
+    let mut p = parse(b"[1, [2, 3], 4, 5]");
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    a.next().unwrap();
+    a.next().unwrap();
+    let rest = Value::from_val(Val::Array(a)).unwrap();
+    assert_eq!(
+        rest,
+        Value::Array(vec![Value::Number("4".into()), Value::Number("5".into())])
+    );
+    p.finalize().unwrap();
+
+    let mut p = parse(b"[[1, 2], [3]]");
+    let Ok(Val::Array(mut a)) = p.value() else {
+        panic!();
+    };
+    let Ok(Some(inner)) = a.next() else {
+        panic!();
+    };
+    assert_eq!(
+        Value::from_val(inner),
+        Ok(Value::Array(vec![
+            Value::Number("1".into()),
+            Value::Number("2".into())
+        ]))
+    );
+    let Ok(Some(inner)) = a.next() else {
+        panic!();
+    };
+    assert_eq!(
+        Value::from_val(inner),
+        Ok(Value::Array(vec![Value::Number("3".into())]))
+    );
+    assert_eq!(a.next(), Ok(None));
+    p.finalize().unwrap();
+}
+
+#[test]
+fn errors() {
+    let mut p = parse(b"[1, {\"a\": [2 3]}]");
+    let err = Value::from_val(p.value().unwrap()).unwrap_err();
+    assert_eq!(err.offset(), 13);
+}
+
+#[test]
+fn as_borrowed() {
+    let n = Value::Number("-12.5".into());
+    let Some(Scalar::Number(n)) = n.as_borrowed() else {
+        panic!();
+    };
+    assert_eq!(n.to_fixed::<1>(), Ok(-125));
+    let s = Value::String("plain".into());
+    let Some(Scalar::String(borrowed)) = s.as_borrowed() else {
+        panic!();
+    };
+    assert_eq!(borrowed.as_unescaped_str(), Some("plain"));
+    assert_eq!(Value::Null.as_borrowed(), Some(Scalar::Null));
+    assert_eq!(Value::String("a\"b".into()).as_borrowed(), None);
+    assert_eq!(Value::Array(vec![]).as_borrowed(), None);
+    for invalid in ["!", "", "01", "1 ", "-", "1e"] {
+        assert_eq!(Value::Number(invalid.into()).as_borrowed(), None);
+    }
+}
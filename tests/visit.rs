@@ -0,0 +1,95 @@
+use tiny_json_parser::{parse, ErrorKind, Flow, Key, Number, Visitor};
+
+// Sums the numbers, skipping members named "skip" and arrays nested in arrays.
+#[derive(Default)]
+struct Sum {
+    total: i64,
+    arrays: usize,
+    exits: usize,
+    stop_at: Option<i64>,
+}
+
+impl<'buf> Visitor<'buf> for Sum {
+    fn visit_number(&mut self, number: Number<'buf>) -> Flow {
+        self.total += number.as_i64().unwrap();
+        match self.stop_at {
+            Some(stop) if self.total >= stop => Flow::Abort,
+            _ => Flow::Continue,
+        }
+    }
+
+    fn visit_key(&mut self, key: Key<'buf>) -> Flow {
+        if key == "skip" {
+            Flow::Skip
+        } else {
+            Flow::Continue
+        }
+    }
+
+    fn enter_array(&mut self) -> Flow {
+        self.arrays += 1;
+        if self.arrays > self.exits + 1 {
+            self.arrays -= 1;
+            Flow::Skip
+        } else {
+            Flow::Continue
+        }
+    }
+
+    fn exit_array(&mut self) -> Flow {
+        self.exits += 1;
+        Flow::Continue
+    }
+}
+
+const JSON: &[u8] =
+    br#"{"a": 1, "skip": {"b": 100}, "c": [2, [200, 300], 3], "skip": 400, "d": {"e": 4}}"#;
+
+#[test]
+fn walk() {
+    let mut p = parse(JSON);
+    let mut sum = Sum::default();
+    assert_eq!(p.walk(&mut sum), Ok(true));
+    p.finalize().unwrap();
+    assert_eq!(sum.total, 10);
+    assert_eq!((sum.arrays, sum.exits), (1, 1));
+}
+
+#[test]
+fn abort() {
+    let mut p = parse(JSON);
+    let mut sum = Sum {
+        stop_at: Some(3),
+        ..Sum::default()
+    };
+    assert_eq!(p.walk(&mut sum), Ok(false));
+    assert_eq!(sum.total, 3);
+}
+
+#[test]
+fn errors() {
+    // Skipped subtrees are validated.
+    let mut p = parse(br#"{"skip": [1 2]}"#);
+    let err = p.walk(&mut Sum::default()).unwrap_err();
+    assert_eq!(
+        (err.kind(), err.offset()),
+        (ErrorKind::ExpectedCommaOrClose, 12)
+    );
+
+    let mut p = parse(b"[1, [2 }]");
+    let err = p.walk(&mut Sum::default()).unwrap_err();
+    assert_eq!(
+        (err.kind(), err.offset()),
+        (ErrorKind::MismatchedBracket, 7)
+    );
+}
+
+#[test]
+fn defaults() {
+    struct Nothing;
+    impl Visitor<'_> for Nothing {}
+
+    let mut p = parse(JSON);
+    assert_eq!(p.walk(&mut Nothing), Ok(true));
+    p.finalize().unwrap();
+}